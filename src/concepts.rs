@@ -1,4 +1,5 @@
 use crate::Digit;
+use alloc::string::String;
 use alloc::vec::Vec;
 
 pub trait DigitOperate {
@@ -8,6 +9,23 @@ pub trait DigitOperate {
     /// Returns one individual [Digit] ot this [DigitOperate] object if it exists.
     fn digit(&self, index: usize) -> Option<Digit>;
 
+    /// Returns the balanced ternary representation (`-`, `0` and `+` characters) of every
+    /// [Digit] of this [DigitOperate] object.
+    ///
+    /// This gives a consistent representation for every implementor, whatever its own `Display`.
+    ///
+    /// ### Examples with [crate::Ternary]
+    /// ```
+    /// use balanced_ternary::Ternary;
+    /// use balanced_ternary::concepts::DigitOperate;
+    ///
+    /// assert_eq!(Ternary::parse("+0-").display_balanced(), "+0-");
+    /// assert_eq!(Ternary::parse("0+0-").display_balanced(), "0+0-");
+    /// ```
+    fn display_balanced(&self) -> String {
+        self.to_digits().iter().map(|d| d.to_char()).collect()
+    }
+
     /// Applies a transformation function to each digit of the balanced ternary number,
     /// returning a new `DigitOperate` object with the transformed digits.
     ///
@@ -66,7 +84,7 @@ pub trait DigitOperate {
     /// ### Arguments
     ///
     /// * `f` - A closure or function that takes a `Digit` and an additional `Digit`,
-    ///         and returns a transformed `Digit`.
+    ///   and returns a transformed `Digit`.
     /// * `other` - An additional `Digit` to be passed to the transformation function `f`.
     ///
     /// ### Returns
@@ -190,3 +208,26 @@ pub trait DigitOperate {
     where
         Self: Sized;
 }
+
+#[cfg(test)]
+#[cfg(all(feature = "tryte", feature = "ternary-store"))]
+#[test]
+fn test_display_balanced() {
+    use crate::{ter, DataTernary, Ter40, Tryte};
+    use alloc::string::ToString;
+
+    let ternary = ter("+0--+0");
+    assert_eq!(ternary.display_balanced(), ternary.to_string());
+
+    let tryte = Tryte::<8>::from("+0-");
+    assert_eq!(tryte.display_balanced(), tryte.to_string());
+    assert_eq!(tryte.display_balanced(), "00000+0-");
+
+    let ter40 = Ter40::from_dec(-1234);
+    assert_eq!(ter40.display_balanced(), ter40.to_string());
+    assert_eq!(ter40.display_balanced().len(), 40);
+
+    let data = DataTernary::from_dec(-7);
+    assert_eq!(data.display_balanced(), data.to_string());
+    assert_eq!(data.display_balanced(), "00-+-");
+}
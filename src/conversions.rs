@@ -7,6 +7,9 @@
 //! - `Digit` <-> `i8`: Converts digits to and from their byte representation.
 //! - `Ternary` <-> `&str` / `String`: Allows parsing and generating ternary numbers from strings.
 //! - `Ternary` <-> `i64`: Converts ternary numbers from/to decimal numbers.
+//! - `Ternary` <-> `i32`, `u32`, `i16`, `u16`, `i8`, `u8`: Converts narrower integers (through `i64`),
+//!   fallible towards the integer with a [RangeError] when the value does not fit.
+//! - `Tryte` <-> `DataTernary`: Moves numbers between fixed size and compact storage (fallible towards `Tryte`).
 //!
 //! The primary goal of these conversions is to simplify working with `Digit` and `Ternary` types by leveraging Rust's `From` and `Into` traits.
 
@@ -16,7 +19,10 @@ use crate::Digit;
 use alloc::string::{String, ToString};
 
 #[cfg(feature = "ternary-string")]
-use crate::Ternary;
+use crate::{RangeError, Ternary};
+
+#[cfg(all(feature = "tryte", feature = "ternary-store"))]
+use crate::{DataTernary, Tryte, TryteLengthError};
 
 impl From<char> for Digit {
     fn from(value: char) -> Self {
@@ -63,6 +69,48 @@ impl From<i64> for Ternary {
     }
 }
 
+#[cfg(feature = "ternary-string")]
+impl From<i32> for Ternary {
+    fn from(value: i32) -> Self {
+        Self::from_dec(value as i64)
+    }
+}
+
+#[cfg(feature = "ternary-string")]
+impl From<u32> for Ternary {
+    fn from(value: u32) -> Self {
+        Self::from_dec(value as i64)
+    }
+}
+
+#[cfg(feature = "ternary-string")]
+impl From<i16> for Ternary {
+    fn from(value: i16) -> Self {
+        Self::from_dec(value as i64)
+    }
+}
+
+#[cfg(feature = "ternary-string")]
+impl From<u16> for Ternary {
+    fn from(value: u16) -> Self {
+        Self::from_dec(value as i64)
+    }
+}
+
+#[cfg(feature = "ternary-string")]
+impl From<i8> for Ternary {
+    fn from(value: i8) -> Self {
+        Self::from_dec(value as i64)
+    }
+}
+
+#[cfg(feature = "ternary-string")]
+impl From<u8> for Ternary {
+    fn from(value: u8) -> Self {
+        Self::from_dec(value as i64)
+    }
+}
+
 #[cfg(feature = "ternary-string")]
 impl From<Ternary> for String {
     fn from(value: Ternary) -> Self {
@@ -76,3 +124,93 @@ impl From<Ternary> for i64 {
         value.to_dec()
     }
 }
+
+/// Implements `TryFrom<Ternary>` for integer types, failing with a [RangeError] when the
+/// value does not fit.
+#[cfg(feature = "ternary-string")]
+macro_rules! impl_try_from_ternary {
+    ($($int:ty),*) => {
+        $(
+            impl TryFrom<Ternary> for $int {
+                type Error = RangeError;
+
+                fn try_from(value: Ternary) -> Result<Self, Self::Error> {
+                    let dec = value.eval_at(3).ok_or(RangeError)?;
+                    Self::try_from(dec).map_err(|_| RangeError)
+                }
+            }
+        )*
+    };
+}
+
+#[cfg(feature = "ternary-string")]
+impl_try_from_ternary!(i32, u32, i16, u16, i8, u8);
+
+#[cfg(all(feature = "tryte", feature = "ternary-store"))]
+impl<const SIZE: usize> From<Tryte<SIZE>> for DataTernary {
+    fn from(value: Tryte<SIZE>) -> Self {
+        Self::from_ternary(value.to_ternary())
+    }
+}
+
+#[cfg(all(feature = "tryte", feature = "ternary-store"))]
+impl<const SIZE: usize> TryFrom<DataTernary> for Tryte<SIZE> {
+    type Error = TryteLengthError;
+
+    /// Fails if the trimmed value of the `DataTernary` has more than SIZE digits.
+    fn try_from(value: DataTernary) -> Result<Self, Self::Error> {
+        Self::from_digits(value.to_ternary().to_digit_slice())
+    }
+}
+
+#[cfg(test)]
+#[cfg(all(feature = "tryte", feature = "ternary-store"))]
+#[test]
+fn test_tryte_data_ternary() {
+    let tryte = Tryte::<10>::from_i64(-12_345);
+    let data = DataTernary::from(tryte);
+    assert_eq!(data.to_dec(), -12_345);
+    assert_eq!(Tryte::<10>::try_from(data.clone()), Ok(tryte));
+    assert_eq!(Tryte::<10>::try_from(DataTernary::from(Tryte::<10>::MAX)), Ok(Tryte::MAX));
+    assert_eq!(Tryte::<10>::try_from(DataTernary::from(Tryte::<10>::ZERO)), Ok(Tryte::ZERO));
+
+    // Leading zeros of the stored chunks do not count.
+    assert_eq!(Tryte::<9>::try_from(data), Err(TryteLengthError));
+    let data = DataTernary::from(Tryte::<10>::from_i64(-1_234));
+    assert_eq!(Tryte::<8>::try_from(data.clone()), Ok(Tryte::<8>::from_i64(-1_234)));
+    assert_eq!(Tryte::<7>::try_from(data), Err(TryteLengthError));
+}
+
+#[cfg(test)]
+#[cfg(feature = "ternary-string")]
+#[test]
+fn test_from_narrow_integers() {
+    assert_eq!(Ternary::from(255u8).to_dec(), 255);
+    assert_eq!(Ternary::from(-128i8).to_dec(), -128);
+    assert_eq!(Ternary::from(u16::MAX).to_dec(), 65_535);
+    assert_eq!(Ternary::from(i16::MIN).to_dec(), -32_768);
+    assert_eq!(Ternary::from(u32::MAX).to_dec(), 4_294_967_295);
+    assert_eq!(Ternary::from(i32::MIN).to_dec(), -2_147_483_648);
+    assert_eq!(Ternary::from(8i32).to_string(), "+0-");
+    assert_eq!(Ternary::from(0u8).to_string(), "0");
+}
+
+#[cfg(test)]
+#[cfg(feature = "ternary-string")]
+#[test]
+fn test_try_into_narrow_integers() {
+    let ternary = Ternary::from_dec(300);
+    assert_eq!(u8::try_from(ternary.clone()), Err(RangeError));
+    assert_eq!(i8::try_from(ternary.clone()), Err(RangeError));
+    assert_eq!(u16::try_from(ternary.clone()), Ok(300));
+    assert_eq!(i32::try_from(ternary), Ok(300));
+
+    assert_eq!(u32::try_from(Ternary::from_dec(-1)), Err(RangeError));
+    assert_eq!(i16::try_from(Ternary::from_dec(-32_768)), Ok(i16::MIN));
+    assert_eq!(i16::try_from(Ternary::from_dec(-32_769)), Err(RangeError));
+    assert_eq!(u32::try_from(Ternary::from(u32::MAX)), Ok(u32::MAX));
+    assert_eq!(i8::try_from(Ternary::parse("000+0-")), Ok(8));
+
+    // Too large even for an i64.
+    assert_eq!(i32::try_from(Ternary::parse(&"+".repeat(45))), Err(RangeError));
+}
@@ -1,5 +1,8 @@
 use core::ops::{Add, BitAnd, BitOr, BitXor, Div, Mul, Neg, Not, Sub};
 
+use crate::ParseTernaryError;
+use alloc::vec::Vec;
+
 #[cfg(feature = "ternary-string")]
 use crate::Ternary;
 
@@ -111,6 +114,36 @@ impl Digit {
         }
     }
 
+    /// Converts the `Digit` into its Setun tape glyph.
+    ///
+    /// - Returns:
+    ///     - `○` for `Digit::Neg`
+    ///     - `·` for `Digit::Zero`
+    ///     - `●` for `Digit::Pos`
+    pub const fn to_char_setun(&self) -> char {
+        match self {
+            Digit::Neg => '○',
+            Digit::Zero => '·',
+            Digit::Pos => '●',
+        }
+    }
+
+    /// Creates a `Digit` from its Setun tape glyph.
+    ///
+    /// - Accepts:
+    ///     - `○` for `Digit::Neg`
+    ///     - `·` for `Digit::Zero`
+    ///     - `●` for `Digit::Pos`
+    /// - Panics if the input character is invalid.
+    pub const fn from_char_setun(c: char) -> Digit {
+        match c {
+            '○' => Digit::Neg,
+            '·' => Digit::Zero,
+            '●' => Digit::Pos,
+            _ => panic!("Invalid value. Expected '○', '·', or '●'."),
+        }
+    }
+
     /// Creates a `Digit` from a character representation.
     ///
     /// - Accepts:
@@ -189,6 +222,24 @@ impl Digit {
         }
     }
 
+    /// Tries to create a `Digit` from its character representation.
+    ///
+    /// - Accepts:
+    ///     - `-` for `Digit::Neg`
+    ///     - `0` for `Digit::Zero`
+    ///     - `+` for `Digit::Pos`
+    /// - Returns a [ParseTernaryError] for any other character.
+    ///
+    /// This is the non-panicking version of [Digit::from_char].
+    pub const fn try_from_char(c: char) -> Result<Digit, ParseTernaryError> {
+        match c {
+            '-' => Ok(Digit::Neg),
+            '0' => Ok(Digit::Zero),
+            '+' => Ok(Digit::Pos),
+            _ => Err(ParseTernaryError),
+        }
+    }
+
     /// Converts the `Digit` into its integer representation.
     ///
     /// - Returns:
@@ -219,6 +270,34 @@ impl Digit {
         }
     }
 
+    /// Tries to create a `Digit` from its integer representation.
+    ///
+    /// - Accepts:
+    ///     - -1 for `Digit::Neg`
+    ///     - 0 for `Digit::Zero`
+    ///     - 1 for `Digit::Pos`
+    /// - Returns a [ParseTernaryError] for any other value.
+    ///
+    /// This is the non-panicking version of [Digit::from_i8].
+    ///
+    /// A `TryFrom<i8>` implementation would conflict with `From<i8>` (which panics), hence
+    /// this method: beware that `Digit::try_from(2i8)` goes through `From<i8>` and panics.
+    ///
+    /// ```
+    /// use balanced_ternary::Digit;
+    ///
+    /// assert_eq!(Digit::try_from_i8(1), Ok(Digit::Pos));
+    /// assert!(Digit::try_from_i8(2).is_err());
+    /// ```
+    pub const fn try_from_i8(i: i8) -> Result<Digit, ParseTernaryError> {
+        match i {
+            -1 => Ok(Digit::Neg),
+            0 => Ok(Digit::Zero),
+            1 => Ok(Digit::Pos),
+            _ => Err(ParseTernaryError),
+        }
+    }
+
     /// Returns the corresponding possible value of the current `Digit`.
     ///
     /// - Returns:
@@ -654,6 +733,32 @@ impl Digit {
         }
     }
 
+    /// Returns the fixed points of a unary operator: the digits `d` such that `f(d) == d`.
+    ///
+    /// # Arguments
+    ///
+    /// * `f` - A unary operator on `Digit`s, such as [Digit::post] or [Digit::ht_not].
+    ///
+    /// # Returns
+    ///
+    /// The fixed points, in the order `Neg`, `Zero`, `Pos`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use balanced_ternary::Digit::{self, Neg, Zero, Pos};
+    ///
+    /// assert_eq!(Digit::fixed_points(Digit::post), vec![]);
+    /// assert_eq!(Digit::fixed_points(|d| -d), vec![Zero]);
+    /// assert_eq!(Digit::fixed_points(Digit::positive), vec![Zero, Pos]);
+    /// ```
+    pub fn fixed_points(f: impl Fn(Digit) -> Digit) -> Vec<Digit> {
+        [Digit::Neg, Digit::Zero, Digit::Pos]
+            .into_iter()
+            .filter(|d| f(*d) == *d)
+            .collect()
+    }
+
     /// Increments the `Digit` value and returns a `Ternary` result.
     ///
     /// - The rules for incrementing are based on ternary arithmetic:
@@ -697,6 +802,93 @@ impl Digit {
     }
 }
 
+/// Lookup table of `Digit + Digit`, indexed by the [Digit::to_unbalanced] values of the operands.
+const ADD_TABLE: [[Digit; 3]; 3] = [
+    [Digit::Pos, Digit::Neg, Digit::Zero],
+    [Digit::Neg, Digit::Zero, Digit::Pos],
+    [Digit::Zero, Digit::Pos, Digit::Neg],
+];
+
+/// Lookup table of `Digit - Digit`, indexed by the [Digit::to_unbalanced] values of the operands.
+const SUB_TABLE: [[Digit; 3]; 3] = [
+    [Digit::Zero, Digit::Pos, Digit::Neg],
+    [Digit::Pos, Digit::Zero, Digit::Neg],
+    [Digit::Pos, Digit::Neg, Digit::Zero],
+];
+
+/// Lookup table of `Digit * Digit`, indexed by the [Digit::to_unbalanced] values of the operands.
+const MUL_TABLE: [[Digit; 3]; 3] = [
+    [Digit::Pos, Digit::Zero, Digit::Neg],
+    [Digit::Zero, Digit::Zero, Digit::Zero],
+    [Digit::Neg, Digit::Zero, Digit::Pos],
+];
+
+/// A set of glyphs to represent the three digits, used by
+/// [Ternary::to_string_with_glyphs](crate::Ternary::to_string_with_glyphs) and
+/// [Ternary::parse_with_glyphs](crate::Ternary::parse_with_glyphs).
+///
+/// The three glyphs should be distinct, otherwise parsing is ambiguous: the first
+/// matching digit (`neg`, then `zero`, then `pos`) is chosen.
+///
+/// # Examples
+///
+/// ```
+/// use balanced_ternary::{Digit, DigitGlyphs};
+///
+/// let nzp = DigitGlyphs { neg: 'N', zero: 'Z', pos: 'P' };
+/// assert_eq!(nzp.to_char(Digit::Neg), 'N');
+/// assert_eq!(nzp.try_from_char('P'), Ok(Digit::Pos));
+/// assert!(nzp.try_from_char('+').is_err());
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct DigitGlyphs {
+    /// The glyph of `Digit::Neg`.
+    pub neg: char,
+    /// The glyph of `Digit::Zero`.
+    pub zero: char,
+    /// The glyph of `Digit::Pos`.
+    pub pos: char,
+}
+
+impl DigitGlyphs {
+    /// `-`, `0` and `+`, as [Digit::to_char].
+    pub const STANDARD: Self = Self { neg: '-', zero: '0', pos: '+' };
+    /// `T`, `0` and `1`, as [Digit::to_char_t].
+    pub const T: Self = Self { neg: 'T', zero: '0', pos: '1' };
+    /// `N`, `Z` and `P`.
+    pub const NZP: Self = Self { neg: 'N', zero: 'Z', pos: 'P' };
+
+    /// Converts a `Digit` into its glyph.
+    pub const fn to_char(&self, digit: Digit) -> char {
+        match digit {
+            Digit::Neg => self.neg,
+            Digit::Zero => self.zero,
+            Digit::Pos => self.pos,
+        }
+    }
+
+    /// Converts a glyph into its `Digit`.
+    ///
+    /// Returns a [ParseTernaryError] if the character is not one of the glyphs.
+    pub const fn try_from_char(&self, c: char) -> Result<Digit, ParseTernaryError> {
+        if c == self.neg {
+            Ok(Digit::Neg)
+        } else if c == self.zero {
+            Ok(Digit::Zero)
+        } else if c == self.pos {
+            Ok(Digit::Pos)
+        } else {
+            Err(ParseTernaryError)
+        }
+    }
+}
+
+impl Default for DigitGlyphs {
+    fn default() -> Self {
+        Self::STANDARD
+    }
+}
+
 impl Neg for Digit {
     type Output = Self;
 
@@ -732,11 +924,7 @@ impl Add<Digit> for Digit {
     /// - Panics:
     ///   - This method does not panic under any circumstances.
     fn add(self, other: Digit) -> Self::Output {
-        match self {
-            Digit::Neg => other.pre(),
-            Digit::Zero => other,
-            Digit::Pos => other.post(),
-        }
+        ADD_TABLE[self.to_unbalanced() as usize][other.to_unbalanced() as usize]
     }
 }
 
@@ -751,11 +939,7 @@ impl Sub<Digit> for Digit {
     /// - Panics:
     ///   - This method does not panic under any circumstances.
     fn sub(self, other: Digit) -> Self::Output {
-        match self {
-            Digit::Neg => other.post(),
-            Digit::Zero => -other,
-            Digit::Pos => other.pre(),
-        }
+        SUB_TABLE[self.to_unbalanced() as usize][other.to_unbalanced() as usize]
     }
 }
 
@@ -778,11 +962,7 @@ impl Mul<Digit> for Digit {
     /// - Returns:
     ///   - A `Digit` instance representing the result of the multiplication.
     fn mul(self, other: Digit) -> Self::Output {
-        match self {
-            Digit::Neg => -other,
-            Digit::Zero => Digit::Zero,
-            Digit::Pos => other,
-        }
+        MUL_TABLE[self.to_unbalanced() as usize][other.to_unbalanced() as usize]
     }
 }
 
@@ -919,3 +1099,82 @@ impl BitXor for Digit {
         }
     }
 }
+
+#[cfg(test)]
+#[test]
+fn test_try_from_i8() {
+    assert_eq!(Digit::try_from_i8(-1), Ok(Digit::Neg));
+    assert_eq!(Digit::try_from_i8(0), Ok(Digit::Zero));
+    assert_eq!(Digit::try_from_i8(1), Ok(Digit::Pos));
+    assert_eq!(Digit::try_from_i8(2), Err(ParseTernaryError));
+    assert_eq!(Digit::try_from_i8(-2), Err(ParseTernaryError));
+    assert_eq!(Digit::try_from_i8(i8::MIN), Err(ParseTernaryError));
+}
+
+#[cfg(test)]
+#[test]
+fn test_try_from_char() {
+    assert_eq!(Digit::try_from_char('-'), Ok(Digit::Neg));
+    assert_eq!(Digit::try_from_char('0'), Ok(Digit::Zero));
+    assert_eq!(Digit::try_from_char('+'), Ok(Digit::Pos));
+    assert_eq!(Digit::try_from_char('1'), Err(ParseTernaryError));
+    assert_eq!(Digit::try_from_char('T'), Err(ParseTernaryError));
+}
+
+#[cfg(test)]
+#[test]
+fn test_arithmetic_tables() {
+    let digits = [Digit::Neg, Digit::Zero, Digit::Pos];
+    for a in digits {
+        for b in digits {
+            let add = match a {
+                Digit::Neg => b.pre(),
+                Digit::Zero => b,
+                Digit::Pos => b.post(),
+            };
+            let sub = match a {
+                Digit::Neg => b.post(),
+                Digit::Zero => -b,
+                Digit::Pos => b.pre(),
+            };
+            let mul = match a {
+                Digit::Neg => -b,
+                Digit::Zero => Digit::Zero,
+                Digit::Pos => b,
+            };
+            assert_eq!(a + b, add);
+            assert_eq!(a - b, sub);
+            assert_eq!(a * b, mul);
+            assert_eq!(a * b, Digit::from_i8(a.to_i8() * b.to_i8()));
+        }
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn test_fixed_points() {
+    use alloc::vec;
+
+    assert_eq!(Digit::fixed_points(Digit::post), vec![]);
+    assert_eq!(Digit::fixed_points(Digit::pre), vec![]);
+    assert_eq!(Digit::fixed_points(|d| d), vec![Digit::Neg, Digit::Zero, Digit::Pos]);
+    assert_eq!(Digit::fixed_points(Digit::not), vec![Digit::Zero]);
+    assert_eq!(Digit::fixed_points(Digit::ht_not), vec![]);
+    assert_eq!(Digit::fixed_points(Digit::possibly), vec![Digit::Neg, Digit::Pos]);
+    assert_eq!(Digit::fixed_points(Digit::necessary), vec![Digit::Neg, Digit::Pos]);
+}
+
+#[cfg(test)]
+#[test]
+fn test_digit_glyphs() {
+    let digits = [Digit::Neg, Digit::Zero, Digit::Pos];
+    for digit in digits {
+        assert_eq!(DigitGlyphs::STANDARD.to_char(digit), digit.to_char());
+        assert_eq!(DigitGlyphs::T.to_char(digit), digit.to_char_t());
+        for glyphs in [DigitGlyphs::STANDARD, DigitGlyphs::T, DigitGlyphs::NZP] {
+            assert_eq!(glyphs.try_from_char(glyphs.to_char(digit)), Ok(digit));
+        }
+    }
+    assert_eq!(DigitGlyphs::default(), DigitGlyphs::STANDARD);
+    assert_eq!(DigitGlyphs::T.try_from_char('+'), Err(ParseTernaryError));
+}
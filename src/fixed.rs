@@ -0,0 +1,222 @@
+use crate::{Digit, Ternary, Zero};
+use alloc::vec;
+use core::fmt::{Display, Formatter};
+use core::ops::{Add, Mul, Neg, Sub};
+
+/// A fixed-point balanced ternary number.
+///
+/// The value is `int + frac / 3^p`, where `p` (the precision) is the number of digits of `frac`.
+///
+/// Operations between two `FixedTernary` with different precisions are performed with the
+/// greatest precision. As for [Ternary], arithmetic operations are computed on `i64`
+/// and panic on overflow.
+///
+/// # Example
+///
+/// ```
+/// use balanced_ternary::{ter, FixedTernary};
+///
+/// let a = FixedTernary::new(ter("+"), ter("+")); // 1 + 1/3
+/// let b = FixedTernary::new(ter("+-"), ter("0-")); // 2 - 1/9
+/// assert_eq!((&a + &b).to_string(), "+0.+-");
+/// assert_eq!((&a + &b).to_f64(), 3.0 + 2.0 / 9.0);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct FixedTernary {
+    /// The integer part.
+    pub int: Ternary,
+    /// The fractional part, one digit per fractional trit.
+    pub frac: Ternary,
+}
+
+impl FixedTernary {
+    /// Creates a new `FixedTernary` from its integer and fractional parts.
+    ///
+    /// The precision is the number of digits of `frac`.
+    pub fn new(int: Ternary, frac: Ternary) -> Self {
+        Self { int, frac }
+    }
+
+    /// Creates a new `FixedTernary` approximating `num / den` with `precision` fractional digits.
+    ///
+    /// See [Ternary::from_ratio] for the rounding rules.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `den` is zero.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use balanced_ternary::FixedTernary;
+    ///
+    /// let half = FixedTernary::from_ratio(1, 2, 3);
+    /// assert_eq!(half.to_string(), "0.+++");
+    /// ```
+    pub fn from_ratio(num: i64, den: i64, precision: usize) -> Self {
+        let (int, frac) = Ternary::from_ratio(num, den, precision);
+        Self::new(int, frac)
+    }
+
+    /// Returns the number of fractional digits.
+    pub fn precision(&self) -> usize {
+        self.frac.log()
+    }
+
+    /// Returns the same value with at least `precision` fractional digits.
+    ///
+    /// If `precision` is smaller than the current precision, the value is returned unchanged.
+    pub fn with_precision(&self, precision: usize) -> Self {
+        let padding = precision.saturating_sub(self.precision());
+        Self::new(
+            self.int.clone(),
+            self.frac.concat(&Ternary::new(vec![Zero; padding])),
+        )
+    }
+
+    /// Converts the `FixedTernary` into its nearest `f64` value.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use balanced_ternary::{ter, FixedTernary};
+    ///
+    /// let value = FixedTernary::new(ter("+-"), ter("-"));
+    /// assert_eq!(value.to_f64(), 2.0 - 1.0 / 3.0);
+    /// ```
+    pub fn to_f64(&self) -> f64 {
+        let mut value = self.int.to_dec() as f64;
+        let mut weight = 1.0;
+        for digit in self.frac.iter() {
+            weight /= 3.0;
+            value += digit.to_i8() as f64 * weight;
+        }
+        value
+    }
+
+    /// Returns the value multiplied by `3^precision`, as an `i64`.
+    fn to_scaled(&self, precision: usize) -> i64 {
+        let fixed = self.with_precision(precision);
+        fixed.int.concat(&fixed.frac).to_dec()
+    }
+
+    /// Creates a `FixedTernary` from a value multiplied by `3^precision`.
+    fn from_scaled(scaled: i64, precision: usize) -> Self {
+        let repr = Ternary::from_dec(scaled).with_length(precision);
+        let split = repr.log() - precision;
+        let digits = repr.to_digit_slice();
+        Self::new(
+            Ternary::new(digits[..split].to_vec()).trim(),
+            Ternary::new(digits[split..].to_vec()),
+        )
+    }
+}
+
+impl Display for FixedTernary {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}.{}", self.int, self.frac)
+    }
+}
+
+impl Neg for &FixedTernary {
+    type Output = FixedTernary;
+
+    fn neg(self) -> Self::Output {
+        FixedTernary::new(-&self.int, -&self.frac)
+    }
+}
+
+impl Add<&FixedTernary> for &FixedTernary {
+    type Output = FixedTernary;
+
+    fn add(self, rhs: &FixedTernary) -> Self::Output {
+        let precision = self.precision().max(rhs.precision());
+        FixedTernary::from_scaled(
+            self.to_scaled(precision)
+                .checked_add(rhs.to_scaled(precision))
+                .expect("Overflow in addition."),
+            precision,
+        )
+    }
+}
+
+impl Sub<&FixedTernary> for &FixedTernary {
+    type Output = FixedTernary;
+
+    fn sub(self, rhs: &FixedTernary) -> Self::Output {
+        let precision = self.precision().max(rhs.precision());
+        FixedTernary::from_scaled(
+            self.to_scaled(precision)
+                .checked_sub(rhs.to_scaled(precision))
+                .expect("Overflow in subtraction."),
+            precision,
+        )
+    }
+}
+
+impl Mul<&FixedTernary> for &FixedTernary {
+    type Output = FixedTernary;
+
+    /// Multiplies two `FixedTernary`.
+    ///
+    /// The exact product has the sum of both precisions: it is rounded to the nearest value
+    /// with the greatest precision of the operands, by dropping the extra least significant
+    /// digits.
+    fn mul(self, rhs: &FixedTernary) -> Self::Output {
+        let precision = self.precision().max(rhs.precision());
+        let extra = self.precision() + rhs.precision() - precision;
+        let product = Ternary::from_dec(
+            self.to_scaled(self.precision())
+                .checked_mul(rhs.to_scaled(rhs.precision()))
+                .expect("Overflow in multiplication."),
+        );
+        let digits = product.to_digit_slice();
+        let kept = digits.len().saturating_sub(extra);
+        FixedTernary::from_scaled(Ternary::new(digits[..kept].to_vec()).to_dec(), precision)
+    }
+}
+
+impl From<Ternary> for FixedTernary {
+    fn from(value: Ternary) -> Self {
+        FixedTernary::new(value, Ternary::new(vec![]))
+    }
+}
+
+impl From<Digit> for FixedTernary {
+    fn from(value: Digit) -> Self {
+        FixedTernary::from(Ternary::new(vec![value]))
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn test_fixed_ternary() {
+    use crate::ter;
+    use alloc::string::ToString;
+
+    let a = FixedTernary::from_ratio(1, 3, 2);
+    let b = FixedTernary::from_ratio(5, 9, 2);
+    assert_eq!(a.to_string(), "0.+0");
+    assert_eq!(b.to_string(), "+.--");
+
+    let sum = &a + &b;
+    assert_eq!(sum.to_string(), "+.0-");
+    assert_eq!(sum.to_f64(), 8.0 / 9.0);
+    assert_eq!((&a - &b).to_f64(), -2.0 / 9.0);
+    assert_eq!((-&a).to_string(), "0.-0");
+
+    // Different precisions
+    let c = FixedTernary::new(ter("+-"), ter("+"));
+    assert_eq!((&c + &b).to_string(), "+0.0-");
+    assert_eq!((&c + &b).precision(), 2);
+
+    // 1/3 * 1/3 = 1/9, 5/9 * 2 = 10/9
+    assert_eq!((&a * &a).to_string(), "0.0+");
+    assert_eq!((&b * &FixedTernary::from(ter("+-"))).to_f64(), 10.0 / 9.0);
+    // 1/9 * 1/9 is rounded to 0
+    let ninth = FixedTernary::from_ratio(1, 9, 2);
+    assert_eq!((&ninth * &ninth).to_string(), "0.00");
+
+    assert_eq!(FixedTernary::from_ratio(-7, 2, 1).to_f64(), -3.0 - 1.0 / 3.0);
+    assert_eq!(FixedTernary::from(Digit::Neg).to_f64(), -1.0);
+}
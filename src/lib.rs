@@ -9,14 +9,14 @@
 //! # Data Structures
 //!
 //! - **`Digit` Enum**:
-//!     Represents a single digit for balanced ternary values, with possible values:
-//!     - `Neg` for -1
-//!     - `Zero` for 0
-//!     - `Pos` for +1
+//!   Represents a single digit for balanced ternary values, with possible values:
+//!   - `Neg` for -1
+//!   - `Zero` for 0
+//!   - `Pos` for +1
 //!
 //! ## Features
 //!
-//! All features are enabled by default.
+//! All features, except `serde`, are enabled by default.
 //!
 //! To enable only some features, use the `default-features` option
 //! in your [dependency declaration](https://doc.rust-lang.org/cargo/reference/features.html#dependency-features):
@@ -39,6 +39,8 @@
 //! Add the structure [Ternary] which is a vector of [Digit]s and a lot of utilities
 //! to manipulate digits into the ternary number. Implements [DigitOperate].
 //!
+//! Also add [FixedTernary], a fixed-point number with fractional trits.
+//!
 //! ### `tryte`
 //!
 //! > Needs the feature `ternary-string`.
@@ -50,15 +52,24 @@
 //! > Needs the feature `ternary-string`.
 //!
 //! Add structures to store ternaries efficiently. These types are provided:
-//! - [DataTernary]: a variable length ternary number stored into [TritsChunk]s,
+//! - [DataTernary]: a variable length ternary number stored into [TritsChunk]s. Implements [DigitOperate],
 //! - [TritsChunk]: a fixed size copy-type 5 digits stored into one byte,
 //! - [Ter40]: a fixed size copy-type 40 digits stored into one 64 bits integer. Implements [DigitOperate].
+//! - [Ter80]: a fixed size copy-type 80 digits stored into one 128 bits integer. Implements [DigitOperate].
+//!
+//! ### `serde`
+//!
+//! > Not enabled by default.
+//!
+//! Implements `Serialize` and `Deserialize` of [serde](https://serde.rs) for [Tryte]`<N>`,
+//! as its fixed width string (e.g. `"000+-0"`).
 //!
 
 #![no_std]
 extern crate alloc;
 
 pub mod concepts;
+pub mod logic;
 
 #[cfg(feature = "ternary-string")]
 use alloc::{format, string::String, string::ToString, vec, vec::Vec};
@@ -72,20 +83,32 @@ use core::{
     cmp::Ordering,
 };
 
-#[cfg(feature = "ternary-string")]
-/// Error returned when parsing a string into a [`Ternary`] fails.
+/// Error returned when parsing a string into a `Ternary`, or a value into a [`Digit`], fails.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct ParseTernaryError;
 
+impl core::fmt::Display for ParseTernaryError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "invalid character in balanced ternary string")
+    }
+}
+
+impl core::error::Error for ParseTernaryError {}
+
+#[cfg(feature = "ternary-string")]
+/// Error returned when a value does not fit into the range of a fixed size type.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RangeError;
+
 #[cfg(feature = "ternary-string")]
-impl Display for ParseTernaryError {
+impl Display for RangeError {
     fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
-        write!(f, "invalid character in balanced ternary string")
+        write!(f, "value out of range for the target type")
     }
 }
 
 #[cfg(feature = "ternary-string")]
-impl Error for ParseTernaryError {}
+impl Error for RangeError {}
 
 /// Provides helper functions for formatting integers in a given radix.
 ///
@@ -119,6 +142,7 @@ mod digit;
 pub use crate::digit::{
     Digit,
     Digit::{Neg, Pos, Zero},
+    DigitGlyphs,
 };
 
 /// Converts a character into a `Digit`.
@@ -236,6 +260,21 @@ pub fn dter(from: &str) -> DataTernary {
 /// Represents a balanced ternary number using a sequence of `Digit`s.
 ///
 /// Provides functions for creating, parsing, converting, and manipulating balanced ternary numbers.
+///
+/// # Empty `Ternary`
+///
+/// A `Ternary` without digits (`Ternary::new(vec![])`) is zero: it is displayed as `"0"`
+/// and the operators treat it as zero. Being structural, `==` between two `Ternary` still
+/// distinguishes it from `"0"`: compare their values or [trim](Ternary::trim) them first.
+///
+/// ```
+/// use balanced_ternary::{ter, Ternary};
+///
+/// let empty = Ternary::new(vec![]);
+/// assert_eq!(empty.to_string(), "0");
+/// assert_eq!(&empty + &ter("+"), ter("+"));
+/// assert_eq!(empty, 0);
+/// ```
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 #[cfg(feature = "ternary-string")]
 pub struct Ternary {
@@ -320,13 +359,94 @@ impl Ternary {
         dec
     }
 
+    /// Converts the `Ternary` into an approximate `f64` value.
+    ///
+    /// Unlike [Ternary::to_dec], this does not overflow on long numbers, but loses precision:
+    /// an `f64` holds 53 significant bits (about 33 digits). Numbers of up to 40 digits are
+    /// rounded to the nearest `f64`, longer ones are approximated. Beyond about 646 digits,
+    /// the value is out of the range of `f64` and the result is an infinity.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use balanced_ternary::{ter, Ternary};
+    ///
+    /// assert_eq!(ter("+--").to_f64(), 5.0);
+    /// let long = Ternary::parse(&"+".repeat(50));
+    /// assert!(long.to_f64() > i64::MAX as f64);
+    /// ```
+    pub fn to_f64(&self) -> f64 {
+        let exact = |digits: &[Digit]| digits.iter().fold(0, |v, d| v * 3 + d.to_i8() as i64);
+        let leading = self.digits.iter().take_while(|d| **d == Zero).count();
+        let digits = &self.digits[leading..];
+        if digits.len() <= 40 {
+            return exact(digits) as f64;
+        }
+        // 3^33 < 2^53: each chunk of 33 digits and the scale are exact `f64` values.
+        let scale = 3_i64.pow(33) as f64;
+        digits
+            .rchunks(33)
+            .rev()
+            .fold(0.0, |value, chunk| value * scale + exact(chunk) as f64)
+    }
+
+    /// Creates a `Ternary` from the nearest integer of an `f64` value, rounding half-way
+    /// cases away from zero. The result is trimmed.
+    ///
+    /// Every finite `f64` is supported: values out of the range of `i64` are converted
+    /// exactly, without going through [Ternary::from_dec]. But an `f64` only holds 53
+    /// significant bits, so a value greater than `2^53` was likely already rounded.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `value` is NaN or infinite.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use balanced_ternary::Ternary;
+    ///
+    /// assert_eq!(Ternary::from_f64(5.4).to_dec(), 5);
+    /// assert_eq!(Ternary::from_f64(-2.5).to_dec(), -3);
+    /// assert_eq!(Ternary::from_f64(1e30).to_f64(), 1e30);
+    /// ```
+    pub fn from_f64(value: f64) -> Self {
+        if !value.is_finite() {
+            panic!("Ternary::from_f64(): Invalid value: {}", value);
+        }
+        if (i64::MIN as f64..i64::MAX as f64).contains(&value) {
+            let int = value as i64;
+            let frac = value - int as f64;
+            return Self::from_dec(match frac {
+                f if f >= 0.5 => int + 1,
+                f if f <= -0.5 => int - 1,
+                _ => int,
+            });
+        }
+        // Out of the range of `i64`, the value is an integer: mantissa * 2^exponent.
+        let bits = value.to_bits();
+        let exponent = ((bits >> 52) & 0x7ff) as usize - 1075;
+        let mantissa = (bits & ((1 << 52) - 1)) | (1 << 52);
+        let mut repr = Self::from_dec(mantissa as i64);
+        for _ in 0..exponent {
+            repr = repr.add_digits(&repr).trim();
+        }
+        if value < 0.0 {
+            -&repr
+        } else {
+            repr
+        }
+    }
+
     /// Creates a balanced ternary number from a decimal integer.
     ///
     /// The input number is converted into its balanced ternary representation,
     /// with digits represented as `Digit`s.
     pub fn from_dec(dec: i64) -> Self {
         let sign = dec.signum();
-        let str = format_radix(dec.abs(), 3);
+        // `format_radix` takes the absolute value itself, which does not overflow on `i64::MIN`.
+        let str = format_radix(dec, 3);
+        let str = str.trim_start_matches('-');
         let mut carry = 0u8;
         let mut repr = Ternary::new(vec![]);
         for digit in str.chars().rev() {
@@ -355,6 +475,36 @@ impl Ternary {
         }
     }
 
+    /// Returns the number of digits needed to represent `value` in balanced ternary.
+    ///
+    /// This is the length of the trimmed [Ternary::from_dec] of a non-zero `value`,
+    /// computed without building it.
+    ///
+    /// # Returns
+    ///
+    /// * `usize` - The minimal number of digits, `0` for zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use balanced_ternary::Ternary;
+    ///
+    /// assert_eq!(Ternary::digits_needed(13), 3);
+    /// assert_eq!(Ternary::digits_needed(14), 4);
+    /// assert_eq!(Ternary::digits_needed(-14), 4);
+    /// ```
+    pub fn digits_needed(value: i64) -> usize {
+        let value = value.unsigned_abs();
+        // The greatest value representable with `len` digits: (3^len - 1) / 2.
+        let mut max = 0u64;
+        let mut len = 0;
+        while max < value {
+            max = max * 3 + 1;
+            len += 1;
+        }
+        len
+    }
+
     /// Converts the balanced ternary number to its unbalanced representation as a string.
     ///
     /// The unbalanced representation treats the digits as standard ternary (0, 1, 2),
@@ -364,7 +514,7 @@ impl Ternary {
     ///
     /// Returns:
     /// * `String` - The unbalanced ternary representation of the number, where each
-    /// digit is one of `0`, `1`, or `2`.
+    ///   digit is one of `0`, `1`, or `2`.
     ///
     /// Example:
     /// ```
@@ -412,6 +562,85 @@ impl Ternary {
         Self::from_dec(i64::from_str_radix(unbalanced, 3).unwrap())
     }
 
+    /// Parses a signed integer written in any `radix` from 2 to 36 into a `Ternary`.
+    ///
+    /// The string follows the format of [i64::from_str_radix] (an optional sign, then digits
+    /// `0`-`9` and letters `a`-`z` or `A`-`Z`), so the value must fit into an `i64`.
+    /// The result is trimmed.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [ParseTernaryError] if `radix` is not in `2..=36`, or if the string is not a
+    /// valid number in this radix.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use balanced_ternary::Ternary;
+    ///
+    /// assert_eq!(Ternary::from_str_radix("ff", 16).unwrap().to_dec(), 255);
+    /// assert_eq!(Ternary::from_str_radix("-12", 3).unwrap().to_string(), "-++");
+    /// assert!(Ternary::from_str_radix("12", 2).is_err());
+    /// ```
+    pub fn from_str_radix(s: &str, radix: u32) -> Result<Ternary, ParseTernaryError> {
+        if !(2..=36).contains(&radix) {
+            return Err(ParseTernaryError);
+        }
+        i64::from_str_radix(s, radix)
+            .map(Self::from_dec)
+            .map_err(|_| ParseTernaryError)
+    }
+
+    /// Parses a string made of balanced ternary, nonary and base-27 segments, and concatenates
+    /// the decoded segments (the first one being the most significant).
+    ///
+    /// # Format
+    ///
+    /// Segments are separated by `|`, each one is a tag, `:` and a payload:
+    ///
+    /// | Tag | Payload                                                   | Example  |
+    /// |-----|-----------------------------------------------------------|----------|
+    /// | `t` | balanced ternary digits (`+`, `0`, `-`), kept as is       | `t:+0-`  |
+    /// | `n` | a signed unbalanced base-9 number (`0`-`8`)               | `n:12`   |
+    /// | `c` | a signed unbalanced base-27 number (`0`-`9`, `A`-`Q`)     | `c:A3`   |
+    ///
+    /// Like [Ternary::from_unbalanced], `n` and `c` payloads are decoded by value into a trimmed
+    /// `Ternary`, so they must fit into an `i64`.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [ParseTernaryError] if a segment has an unknown tag, an invalid or empty
+    /// payload, or a value which does not fit into an `i64`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use balanced_ternary::Ternary;
+    ///
+    /// // n:12 = 11 = "++-", c:A3 = 273 = "+0+0+0"
+    /// let ternary = Ternary::parse_mixed("t:+0-|n:12|c:A3").unwrap();
+    /// assert_eq!(ternary.to_string(), "+0-++-+0+0+0");
+    /// assert!(Ternary::parse_mixed("x:12").is_err());
+    /// ```
+    pub fn parse_mixed(s: &str) -> Result<Ternary, ParseTernaryError> {
+        let mut parts = Vec::new();
+        for segment in s.split('|') {
+            let (tag, payload) = segment.split_once(':').ok_or(ParseTernaryError)?;
+            let radix = match tag {
+                "t" if !payload.is_empty() => {
+                    parts.push(payload.parse()?);
+                    continue;
+                }
+                "n" => 9,
+                "c" => 27,
+                _ => return Err(ParseTernaryError),
+            };
+            let value = i64::from_str_radix(payload, radix).map_err(|_| ParseTernaryError)?;
+            parts.push(Self::from_dec(value));
+        }
+        Ok(Self::concat_all(&parts))
+    }
+
     /// Removes leading `Zero` digits from the `Ternary` number, effectively trimming
     /// it down to its simplest representation. The resulting `Ternary` number
     /// will still represent the same value.
@@ -434,7 +663,7 @@ impl Ternary {
     ///
     /// This method does not mutate the original `Ternary` object but returns a new representation.
     pub fn trim(&self) -> Self {
-        if self.to_dec() == 0 {
+        if digits_sign(&self.digits) == Zero {
             return Ternary::parse("0");
         }
         let mut repr = Ternary::new(vec![]);
@@ -450,6 +679,135 @@ impl Ternary {
         repr
     }
 
+    /// Checks whether the `Ternary` is in its canonical (trimmed) form.
+    ///
+    /// A canonical `Ternary` has no leading `Zero` digit, except the single-digit `"0"`.
+    /// It is the form returned by [Ternary::trim], which can be skipped for such numbers.
+    ///
+    /// # Returns
+    ///
+    /// * `bool` - `true` if `self.trim() == *self`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use balanced_ternary::ter;
+    ///
+    /// assert!(ter("+0-").is_canonical());
+    /// assert!(ter("0").is_canonical());
+    /// assert!(!ter("0+0-").is_canonical());
+    /// assert!(!ter("00").is_canonical());
+    /// ```
+    pub fn is_canonical(&self) -> bool {
+        match self.digits.as_slice() {
+            [] => false,
+            [_] => true,
+            [first, ..] => *first != Zero,
+        }
+    }
+
+    /// Returns a new `Ternary` with the digits in reverse order: the most significant digit
+    /// becomes the least significant one.
+    ///
+    /// This is a structural operation on the digits, not an arithmetic one: it is neither a
+    /// negation nor a complement, and the value generally changes (`+00` is 9, `00+` is 1).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use balanced_ternary::ter;
+    ///
+    /// assert_eq!(ter("+0-").reversed().to_string(), "-0+");
+    /// assert_eq!(ter("+00").reversed().to_dec(), 1);
+    /// ```
+    pub fn reversed(&self) -> Ternary {
+        Ternary::new(self.digits.iter().rev().cloned().collect())
+    }
+
+    /// Checks whether the digits of the `Ternary` read the same forwards and backwards.
+    ///
+    /// Leading `Zero` digits are significant: use [Ternary::is_palindrome_trimmed] to ignore them.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use balanced_ternary::ter;
+    ///
+    /// assert!(ter("+0+").is_palindrome());
+    /// assert!(!ter("+0-").is_palindrome());
+    /// assert!(!ter("0+0+").is_palindrome());
+    /// ```
+    pub fn is_palindrome(&self) -> bool {
+        self.digits.iter().eq(self.digits.iter().rev())
+    }
+
+    /// Checks whether the digits of the `Ternary`, without its leading `Zero` digits,
+    /// read the same forwards and backwards.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use balanced_ternary::ter;
+    ///
+    /// assert!(ter("0+0+").is_palindrome_trimmed());
+    /// assert!(!ter("0+0-").is_palindrome_trimmed());
+    /// ```
+    pub fn is_palindrome_trimmed(&self) -> bool {
+        let leading = self.digits.iter().take_while(|d| **d == Zero).count();
+        let digits = &self.digits[leading..];
+        digits.iter().eq(digits.iter().rev())
+    }
+
+    /// Removes the leading `Zero` digits of the `Ternary` number, in place.
+    ///
+    /// Same as [Ternary::trim], without allocating a new `Ternary`: a number whose digits are
+    /// all `Zero` (or without digits) becomes `"0"`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use balanced_ternary::ter;
+    ///
+    /// let mut ternary = ter("00+-");
+    /// ternary.trim_in_place();
+    /// assert_eq!(ternary.to_string(), "+-");
+    /// ```
+    pub fn trim_in_place(&mut self) {
+        let leading = self.digits.iter().take_while(|d| **d == Zero).count();
+        if leading == self.digits.len() {
+            self.digits.clear();
+            self.digits.push(Zero);
+        } else {
+            self.digits.drain(..leading);
+        }
+    }
+
+    /// Computes a deterministic 64-bit hash of the value of the `Ternary` number.
+    ///
+    /// Leading `Zero` digits are ignored, so equal values hash equally (`"00+"` and `"+"`),
+    /// unlike the derived [Hash] which is structural. The hash is a 64-bit FNV-1a over the
+    /// significant digits: it does not depend on a [core::hash::Hasher] or on the platform,
+    /// and can be persisted.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use balanced_ternary::ter;
+    ///
+    /// assert_eq!(ter("00+-").stable_hash(), ter("+-").stable_hash());
+    /// assert_ne!(ter("+-").stable_hash(), ter("-+").stable_hash());
+    /// ```
+    pub fn stable_hash(&self) -> u64 {
+        const FNV_OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+        const FNV_PRIME: u64 = 0x0000_0100_0000_01b3;
+        self.digits
+            .iter()
+            .skip_while(|d| **d == Zero)
+            .fold(FNV_OFFSET_BASIS, |hash, digit| {
+                (hash ^ digit.to_i8() as u8 as u64).wrapping_mul(FNV_PRIME)
+            })
+    }
+
     /// Adjusts the representation of the `Ternary` number to have a fixed number of digits.
     ///
     /// If the current `Ternary` has fewer digits than the specified `length`, leading zero digits
@@ -492,6 +850,180 @@ impl Ternary {
         repr
     }
 
+    /// Extends the `Ternary` number to `length` digits by repeating its most significant digit.
+    ///
+    /// This mimics the sign extension of binary two's complement, but balanced ternary has no
+    /// sign digit: the value is preserved only when the leading digit is `Zero` (the result
+    /// is then the same as [Ternary::with_length]). Otherwise, the value changes
+    /// (`"-+"` = -2 becomes `"---+"` = -38).
+    ///
+    /// # Arguments
+    ///
+    /// * `length` - The desired length of the `Ternary` number.
+    ///
+    /// # Returns
+    ///
+    /// * `Self` - A new `Ternary` with `length` digits, or unchanged if it is already longer.
+    ///   An empty `Ternary` is extended with `Zero` digits.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use balanced_ternary::ter;
+    ///
+    /// assert_eq!(ter("-+").sign_extend(4).to_string(), "---+");
+    /// assert_eq!(ter("-+").with_length(4).to_string(), "00-+");
+    /// assert_eq!(ter("0+").sign_extend(4).to_string(), "000+");
+    /// ```
+    pub fn sign_extend(&self, length: usize) -> Self {
+        let msd = self.digits.first().copied().unwrap_or(Zero);
+        let mut repr = Ternary::new(vec![msd; length.saturating_sub(self.log())]);
+        repr.digits.extend(self.digits.iter().cloned());
+        repr
+    }
+
+    /// Returns the greatest `Ternary` number of `len` digits: `len` `Pos` digits.
+    ///
+    /// With [Ternary::min_for_len], gives the range of the numbers representable with `len`
+    /// digits: `-(3^len - 1) / 2..=(3^len - 1) / 2`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use balanced_ternary::Ternary;
+    ///
+    /// assert_eq!(Ternary::max_for_len(3).to_string(), "+++");
+    /// assert_eq!(Ternary::max_for_len(3).to_dec(), 13);
+    /// ```
+    pub fn max_for_len(len: usize) -> Ternary {
+        Ternary::new(vec![Pos; len])
+    }
+
+    /// Returns the least `Ternary` number of `len` digits: `len` `Neg` digits.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use balanced_ternary::Ternary;
+    ///
+    /// assert_eq!(Ternary::min_for_len(3).to_dec(), -13);
+    /// ```
+    pub fn min_for_len(len: usize) -> Ternary {
+        Ternary::new(vec![Neg; len])
+    }
+
+    /// Iterates over all the `3^len` `Ternary` numbers of `len` digits, in counting order.
+    ///
+    /// The iteration starts at [Ternary::min_for_len] and ends at [Ternary::max_for_len],
+    /// each number being the successor of the previous one. Useful for exhaustive tests.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use balanced_ternary::Ternary;
+    ///
+    /// let all = Ternary::all_of_length(2).map(|t| t.to_dec()).collect::<Vec<_>>();
+    /// assert_eq!(all, (-4..=4).collect::<Vec<_>>());
+    /// ```
+    pub fn all_of_length(len: usize) -> impl Iterator<Item = Ternary> {
+        core::iter::successors(Some(Ternary::min_for_len(len)), |previous| {
+            let mut next = previous.clone();
+            for digit in next.digits.iter_mut().rev() {
+                if *digit == Pos {
+                    *digit = Neg;
+                } else {
+                    *digit = digit.post();
+                    return Some(next);
+                }
+            }
+            None
+        })
+    }
+
+    /// Returns the zero `Ternary` number of `len` digits: `len` `Zero` digits.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use balanced_ternary::Ternary;
+    ///
+    /// assert_eq!(Ternary::zero_for_len(3).to_string(), "000");
+    /// ```
+    pub fn zero_for_len(len: usize) -> Ternary {
+        Ternary::new(vec![Zero; len])
+    }
+
+    /// Reinterprets the `Ternary` number into exactly `width` digits, with wraparound.
+    ///
+    /// The value is reduced modulo `3^width` into the balanced range
+    /// `-(3^width - 1) / 2..=(3^width - 1) / 2`, as a fixed-width ternary register would do.
+    /// In balanced ternary, this amounts to keeping the `width` least significant digits.
+    ///
+    /// # Arguments
+    ///
+    /// * `width` - The number of digits of the result.
+    ///
+    /// # Returns
+    ///
+    /// * `Self` - A new `Ternary` object with exactly `width` digits.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use balanced_ternary::Ternary;
+    ///
+    /// // 2 digits hold the values -4..=4, and 14 ≡ -4 (mod 9).
+    /// let wrapped = Ternary::from_dec(14).to_fixed_width_wrapping(2);
+    /// assert_eq!(wrapped.to_string(), "--");
+    /// assert_eq!(wrapped.to_dec(), -4);
+    ///
+    /// let padded = Ternary::from_dec(4).to_fixed_width_wrapping(4);
+    /// assert_eq!(padded.to_string(), "00++");
+    /// ```
+    pub fn to_fixed_width_wrapping(&self, width: usize) -> Ternary {
+        if width >= self.log() {
+            return self.with_length(width);
+        }
+        Ternary::new(self.digits[self.log() - width..].to_vec())
+    }
+
+    /// Computes the complement of the `Ternary` number to `3^width`, on exactly `width` digits.
+    ///
+    /// As for [Ternary::to_fixed_width_wrapping], `3^width - self` is reduced modulo `3^width`
+    /// into the balanced range of `width` digits. In balanced ternary, this amounts to negating
+    /// each digit: `a - b` can then be computed as the wrapped sum of `a` and the complement of
+    /// `b`, as a fixed-width ternary register would do.
+    ///
+    /// If `self` has more than `width` digits, only its `width` least significant digits
+    /// are complemented (the value is first wrapped).
+    ///
+    /// # Arguments
+    ///
+    /// * `width` - The number of digits of the result.
+    ///
+    /// # Returns
+    ///
+    /// * `Self` - A new `Ternary` object with exactly `width` digits.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use balanced_ternary::Ternary;
+    ///
+    /// let complement = Ternary::from_dec(5).complement(4);
+    /// assert_eq!(complement.to_string(), "0-++");
+    /// // 81 - 5 = 76 ≡ -5 (mod 81)
+    /// assert_eq!(complement.to_dec(), -5);
+    ///
+    /// // 7 - 5 = 7 + complement(5)
+    /// let seven = Ternary::from_dec(7);
+    /// let difference = (&seven + &complement).to_fixed_width_wrapping(4);
+    /// assert_eq!(difference.to_dec(), 2);
+    /// ```
+    pub fn complement(&self, width: usize) -> Ternary {
+        -&self.to_fixed_width_wrapping(width)
+    }
+
     /// Converts the `Ternary` number into a string representation by applying a given
     /// transformation function to each digit of the ternary number.
     ///
@@ -524,7 +1056,11 @@ impl Ternary {
     /// * The function provides flexibility to define custom string representations
     ///   for the ternary number digits.
     /// * Call to `Ternary::to_string()` is equivalent to `Ternary::to_string_repr(Digit::to_char)`.
+    /// * An empty `Ternary` is represented as a single `Zero` digit.
     pub fn to_string_repr<F: Fn(&Digit) -> char>(&self, transform: F) -> String {
+        if self.digits.is_empty() {
+            return String::from(transform(&Zero));
+        }
         let mut str = String::new();
         for digit in self.digits.iter() {
             str.push(transform(digit));
@@ -532,18 +1068,47 @@ impl Ternary {
         str
     }
 
-    /// Concatenates the current `Ternary` number with another `Ternary` number.
+    /// Writes the digits of the `Ternary` number (`+`, `0`, `-`) into a [core::fmt::Write]
+    /// sink, without building a `String`.
     ///
-    /// This function appends the digits of the provided `Ternary` object to the digits
-    /// of the current `Ternary` object, creating a new `Ternary` number as the result.
-    ///
-    /// # Arguments
+    /// This lets `no_std` users stream the representation into a fixed buffer. The output
+    /// is the same as `Ternary::to_string()`: an empty `Ternary` is written as `"0"`.
     ///
-    /// * `other` - A reference to the `Ternary` number to be concatenated to the current one.
-    ///
-    /// # Returns
+    /// # Errors
     ///
-    /// * `Ternary` - A new `Ternary` object formed by concatenating the digits.
+    /// Returns the [core::fmt::Error] of the sink, if any.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use balanced_ternary::Ternary;
+    ///
+    /// let mut out = String::from("value: ");
+    /// Ternary::parse("+0-").write_to(&mut out).unwrap();
+    /// assert_eq!(out, "value: +0-");
+    /// ```
+    pub fn write_to(&self, w: &mut impl core::fmt::Write) -> core::fmt::Result {
+        if self.digits.is_empty() {
+            return w.write_char(Zero.to_char());
+        }
+        for digit in self.digits.iter() {
+            w.write_char(digit.to_char())?;
+        }
+        Ok(())
+    }
+
+    /// Concatenates the current `Ternary` number with another `Ternary` number.
+    ///
+    /// This function appends the digits of the provided `Ternary` object to the digits
+    /// of the current `Ternary` object, creating a new `Ternary` number as the result.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - A reference to the `Ternary` number to be concatenated to the current one.
+    ///
+    /// # Returns
+    ///
+    /// * `Ternary` - A new `Ternary` object formed by concatenating the digits.
     ///
     /// # Examples
     ///
@@ -562,313 +1127,3359 @@ impl Ternary {
         t.digits.extend(other.digits.iter().cloned());
         t
     }
-}
 
-#[cfg(feature = "ternary-string")]
-impl DigitOperate for Ternary {
-    fn to_digits(&self) -> Vec<Digit> {
-        self.to_digit_slice().to_vec()
+    /// Concatenates several `Ternary` numbers, in order, into one `Ternary`.
+    ///
+    /// The result is built with a single allocation sized to the total length, which is
+    /// more efficient than chaining [Ternary::concat] calls.
+    ///
+    /// # Arguments
+    ///
+    /// * `parts` - The `Ternary` numbers to concatenate, the first one being the most significant.
+    ///
+    /// # Returns
+    ///
+    /// * `Ternary` - A new `Ternary` object formed by concatenating the digits of all parts.
+    ///   An empty slice gives an empty `Ternary`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use balanced_ternary::Ternary;
+    ///
+    /// let parts = [Ternary::parse("+0"), Ternary::parse("-"), Ternary::parse("0+")];
+    /// assert_eq!(Ternary::concat_all(&parts).to_string(), "+0-0+");
+    /// ```
+    pub fn concat_all(parts: &[Ternary]) -> Ternary {
+        let length = parts.iter().map(Ternary::log).sum();
+        let mut digits = Vec::with_capacity(length);
+        for part in parts {
+            digits.extend(part.digits.iter().cloned());
+        }
+        Ternary::new(digits)
     }
 
-    fn digit(&self, index: usize) -> Option<Digit> {
-        self.get_digit(index).cloned()
+    /// Appends a digit in place, as the new least significant digit.
+    ///
+    /// This multiplies the value by 3 and adds `digit`, like [Ternary::concat] with a single
+    /// digit would, but without allocating a new `Ternary`. See also the [Extend] implementation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use balanced_ternary::{ter, Pos};
+    ///
+    /// let mut ternary = ter("+0");
+    /// ternary.push(Pos);
+    /// assert_eq!(ternary.to_string(), "+0+");
+    /// assert_eq!(ternary.to_dec(), 10);
+    /// ```
+    pub fn push(&mut self, digit: Digit) {
+        self.digits.push(digit);
     }
 
-    fn each(&self, f: impl Fn(Digit) -> Digit) -> Self {
-        let mut repr = Ternary::new(vec![]);
-        for digit in self.digits.iter() {
-            repr.digits.push(f(*digit));
+    /// Removes the least significant digit in place and returns it, or `None` if the
+    /// `Ternary` is empty.
+    ///
+    /// This is the inverse of [Ternary::push].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use balanced_ternary::{ter, Neg};
+    ///
+    /// let mut ternary = ter("+0-");
+    /// assert_eq!(ternary.pop(), Some(Neg));
+    /// assert_eq!(ternary.to_string(), "+0");
+    /// ```
+    pub fn pop(&mut self) -> Option<Digit> {
+        self.digits.pop()
+    }
+
+    /// Keeps only the `len` least significant digits, dropping the most significant ones.
+    ///
+    /// Unlike [Ternary::with_length], which never removes digits, this changes the value
+    /// (it is reduced modulo `3^len`, as with [Ternary::to_fixed_width_wrapping]). Nothing
+    /// happens if the `Ternary` has `len` digits or fewer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use balanced_ternary::ter;
+    ///
+    /// let mut ternary = ter("+++--+");
+    /// ternary.truncate(3);
+    /// assert_eq!(ternary.to_string(), "--+");
+    /// ternary.truncate(5);
+    /// assert_eq!(ternary.to_string(), "--+");
+    /// ```
+    pub fn truncate(&mut self, len: usize) {
+        let excess = self.log().saturating_sub(len);
+        self.digits.drain(..excess);
+    }
+
+    /// Splits the `Ternary` into its high and low parts: the low part holds the `index`
+    /// least significant digits and the high part holds the others.
+    ///
+    /// `high.concat(&low)` gives back the original digits.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is greater than [Ternary::log].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use balanced_ternary::ter;
+    ///
+    /// let (high, low) = ter("+0-+--").split_at(2);
+    /// assert_eq!(high.to_string(), "+0-+");
+    /// assert_eq!(low.to_string(), "--");
+    /// assert_eq!(high.concat(&low), ter("+0-+--"));
+    /// ```
+    pub fn split_at(&self, index: usize) -> (Ternary, Ternary) {
+        if index > self.log() {
+            panic!(
+                "Ternary::split_at(): the index is {} but the length is {}.",
+                index,
+                self.log()
+            );
         }
-        repr
+        let (high, low) = self.digits.split_at(self.log() - index);
+        (Ternary::new(high.to_vec()), Ternary::new(low.to_vec()))
     }
 
-    fn each_with(&self, f: impl Fn(Digit, Digit) -> Digit, other: Digit) -> Self {
-        let mut repr = Ternary::new(vec![]);
+    /// Splits the `Ternary` into `Tryte<SIZE>` chunks, least significant chunk first.
+    ///
+    /// The digits are grouped by `SIZE` from the right, and the last (most significant) group
+    /// is left-padded with `Zero` digits. Like [DataTernary](crate::DataTernary) does with its
+    /// chunks of 5 digits, but with an arbitrary width.
+    ///
+    /// # Returns
+    ///
+    /// * `Vec<Tryte<SIZE>>` - The chunks, the first one holding the least significant digits.
+    ///   An empty `Ternary` gives an empty vector.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if `SIZE` is 0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use balanced_ternary::{Ternary, Tryte};
+    ///
+    /// let trytes = Ternary::parse("+-0+-0+").to_trytes::<3>();
+    /// assert_eq!(trytes, vec![Tryte::<3>::from("-0+"), Tryte::from("-0+"), Tryte::from("00+")]);
+    /// ```
+    #[cfg(feature = "tryte")]
+    pub fn to_trytes<const SIZE: usize>(&self) -> Vec<Tryte<SIZE>> {
+        self.digits
+            .rchunks(SIZE)
+            .map(|chunk| {
+                let mut digits = [Zero; SIZE];
+                digits[SIZE - chunk.len()..].copy_from_slice(chunk);
+                Tryte::new(digits)
+            })
+            .collect()
+    }
+
+    /// Reassembles `Tryte<SIZE>` chunks, least significant chunk first, into one `Ternary`.
+    ///
+    /// This is the reverse of [Ternary::to_trytes]. Like [DataTernary](crate::DataTernary),
+    /// the resulting `Ternary` is always trimmed.
+    ///
+    /// # Arguments
+    ///
+    /// * `chunks` - The chunks, the first one holding the least significant digits.
+    ///
+    /// # Returns
+    ///
+    /// * `Ternary` - The trimmed concatenation of the chunks. Only `Zero` digits (or no chunk at all)
+    ///   give `"0"`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use balanced_ternary::{Ternary, Tryte};
+    ///
+    /// let ternary = Ternary::parse("+-0+-0+");
+    /// assert_eq!(Ternary::from_trytes(&ternary.to_trytes::<3>()), ternary);
+    /// assert_eq!(Ternary::from_trytes(&[Tryte::<3>::from("-0+"), Tryte::from("000")]).to_string(), "-0+");
+    /// ```
+    #[cfg(feature = "tryte")]
+    pub fn from_trytes<const SIZE: usize>(chunks: &[Tryte<SIZE>]) -> Ternary {
+        let mut digits = Vec::with_capacity(chunks.len() * SIZE);
+        for chunk in chunks.iter().rev() {
+            digits.extend_from_slice(chunk.as_array());
+        }
+        Ternary::new(digits).trim()
+    }
+
+    /// Interleaves the digits of the current `Ternary` with the digits of another `Ternary`.
+    ///
+    /// Both operands are first left-padded with `Zero` to the same length, then the digits
+    /// are taken alternately, starting with the current `Ternary`.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - A reference to the `Ternary` providing the odd-position digits.
+    ///
+    /// # Returns
+    ///
+    /// * `Ternary` - A new `Ternary` object with twice as many digits as the longest operand.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use balanced_ternary::Ternary;
+    ///
+    /// let a = Ternary::parse("+0-");
+    /// let b = Ternary::parse("--");
+    /// assert_eq!(a.interleave(&b).to_string(), "+00---");
+    /// ```
+    pub fn interleave(&self, other: &Ternary) -> Ternary {
+        let length = self.log().max(other.log());
+        let a = self.with_length(length);
+        let b = other.with_length(length);
+        let mut t = Ternary::new(vec![]);
+        for (d_a, d_b) in a.digits.iter().zip(b.digits.iter()) {
+            t.digits.push(*d_a);
+            t.digits.push(*d_b);
+        }
+        t
+    }
+
+    /// Splits the digits of the current `Ternary` into its even-position and odd-position
+    /// subsequences.
+    ///
+    /// Positions are counted **from the left**, starting at `0`. This is the inverse of
+    /// [Ternary::interleave] for even-length inputs.
+    ///
+    /// # Returns
+    ///
+    /// * `(Ternary, Ternary)` - The even-position digits and the odd-position digits.
+    ///
+    /// # Notes
+    ///
+    /// For an odd-length `Ternary`, the first (even-position) part holds one more digit
+    /// than the second one. An empty part is returned as an empty `Ternary`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use balanced_ternary::Ternary;
+    ///
+    /// let (even, odd) = Ternary::parse("+00---").deinterleave();
+    /// assert_eq!(even.to_string(), "+0-");
+    /// assert_eq!(odd.to_string(), "0--");
+    ///
+    /// let (even, odd) = Ternary::parse("+0-").deinterleave();
+    /// assert_eq!(even.to_string(), "+-");
+    /// assert_eq!(odd.to_string(), "0");
+    /// ```
+    pub fn deinterleave(&self) -> (Ternary, Ternary) {
+        let mut even = Ternary::new(vec![]);
+        let mut odd = Ternary::new(vec![]);
+        for (i, digit) in self.digits.iter().enumerate() {
+            if i % 2 == 0 {
+                even.digits.push(*digit);
+            } else {
+                odd.digits.push(*digit);
+            }
+        }
+        (even, odd)
+    }
+
+    /// Divides the current `Ternary` by another `Ternary` using balanced ternary long division.
+    ///
+    /// Unlike the `/` operator, which goes through `i64`, this method works directly on the
+    /// digits and is correct for operands of any length.
+    ///
+    /// As for Rust integers, the quotient is truncated toward zero and the remainder has the
+    /// sign of the dividend, so that `quotient * rhs + remainder == self`.
+    ///
+    /// # Arguments
+    ///
+    /// * `rhs` - A reference to the `Ternary` divisor.
+    ///
+    /// # Returns
+    ///
+    /// * `(Ternary, Ternary)` - The trimmed quotient and remainder.
+    ///
+    /// # Panics
+    ///
+    /// Panics with "Cannot divide by zero." if `rhs` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use balanced_ternary::Ternary;
+    ///
+    /// let (q, r) = Ternary::from_dec(17).long_div(&Ternary::from_dec(5));
+    /// assert_eq!((q.to_dec(), r.to_dec()), (3, 2));
+    ///
+    /// let (q, r) = Ternary::from_dec(-17).long_div(&Ternary::from_dec(5));
+    /// assert_eq!((q.to_dec(), r.to_dec()), (-3, -2));
+    /// ```
+    pub fn long_div(&self, rhs: &Ternary) -> (Ternary, Ternary) {
+        let sign = digits_sign(&self.digits);
+        let rhs_sign = digits_sign(&rhs.digits);
+        if rhs_sign == Zero {
+            panic!("Cannot divide by zero.");
+        }
+        let divisor = if rhs_sign == Neg { -rhs } else { rhs.clone() };
+        let neg_divisor = -&divisor;
+
+        let mut quotient = Ternary::parse("0");
+        let mut remainder = Ternary::parse("0");
         for digit in self.digits.iter() {
-            repr.digits.push(f(*digit, other));
+            // Works on the absolute value of the dividend.
+            remainder.digits.push(if sign == Neg { -*digit } else { *digit });
+            let mut q = 0;
+            while digits_sign(&remainder.digits) == Neg {
+                remainder = remainder.add_digits(&divisor);
+                q -= 1;
+            }
+            while digits_cmp(&remainder.digits, &divisor.digits) != Ordering::Less {
+                remainder = remainder.add_digits(&neg_divisor);
+                q += 1;
+            }
+            quotient.digits.push(Zero);
+            quotient = quotient.add_digits(&Ternary::from_dec(q));
         }
-        repr
+
+        if sign != rhs_sign {
+            quotient = -&quotient;
+        }
+        if sign == Neg {
+            remainder = -&remainder;
+        }
+        (quotient.trim(), remainder.trim())
     }
 
-    fn each_zip(&self, f: impl Fn(Digit, Digit) -> Digit, other: Self) -> Self {
-        if self.digits.len() < other.digits.len() {
-            return other.each_zip(f, self.clone());
+    /// Checks whether the current `Ternary` is a multiple of `d`.
+    ///
+    /// When `d` is a power of three (`±3^k`), only the `k` least significant digits are
+    /// checked: they must all be `Zero`. Otherwise, the remainder of [Ternary::long_div]
+    /// is checked, so this is correct for operands of any length.
+    ///
+    /// # Arguments
+    ///
+    /// * `d` - A reference to the `Ternary` divisor.
+    ///
+    /// # Panics
+    ///
+    /// Panics with "Cannot divide by zero." if `d` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use balanced_ternary::Ternary;
+    ///
+    /// assert!(Ternary::parse("+-0").is_divisible_by(&Ternary::parse("+0")));
+    /// assert!(Ternary::from_dec(35).is_divisible_by(&Ternary::from_dec(-7)));
+    /// assert!(!Ternary::from_dec(35).is_divisible_by(&Ternary::from_dec(3)));
+    /// ```
+    pub fn is_divisible_by(&self, d: &Ternary) -> bool {
+        if digits_sign(&d.digits) == Zero {
+            panic!("Cannot divide by zero.");
         }
-        let other = other.with_length(self.digits.len());
-        let mut repr = Ternary::new(vec![]);
-        for (i, digit) in self.digits.iter().rev().enumerate() {
-            let d_other = other.get_digit(i).unwrap();
-            let res = f(*digit, *d_other);
-            repr.digits.push(res);
+        if digits_sign(&self.digits) == Zero {
+            return true;
         }
-        repr.digits.reverse();
-        repr
+        let divisor = d.trim();
+        if divisor.digits[1..].iter().all(|digit| *digit == Zero) {
+            let power = divisor.log() - 1;
+            return power <= self.log()
+                && self.digits[self.log() - power..].iter().all(|digit| *digit == Zero);
+        }
+        digits_sign(&self.long_div(d).1.digits) == Zero
     }
 
-    fn each_zip_carry(
-        &self,
-        f: impl Fn(Digit, Digit, Digit) -> (Digit, Digit),
-        other: Self,
-    ) -> Self {
-        if self.digits.len() < other.digits.len() {
-            return other.each_zip_carry(f, self.clone());
+    /// Computes the additive inverse of the `Ternary` number modulo `modulus`.
+    ///
+    /// The result is `(modulus - self) mod modulus`, in the range `0..modulus`, so that
+    /// `self + self.mod_neg(modulus)` is a multiple of `modulus`. The inverse of zero is zero.
+    ///
+    /// # Arguments
+    ///
+    /// * `modulus` - A reference to a strictly positive `Ternary` modulus.
+    ///
+    /// # Returns
+    ///
+    /// * `Ternary` - The trimmed inverse, in `0..modulus`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `modulus` is not strictly positive.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use balanced_ternary::Ternary;
+    ///
+    /// let thirteen = Ternary::from_dec(13);
+    /// assert_eq!(Ternary::from_dec(5).mod_neg(&thirteen).to_dec(), 8);
+    /// assert_eq!(Ternary::from_dec(-5).mod_neg(&thirteen).to_dec(), 5);
+    /// assert_eq!(Ternary::from_dec(26).mod_neg(&thirteen).to_dec(), 0);
+    /// ```
+    pub fn mod_neg(&self, modulus: &Ternary) -> Ternary {
+        let reduced = self.modulo(modulus, "mod_neg");
+        if digits_sign(&reduced.digits) == Zero {
+            return reduced;
         }
-        let other = other.with_length(self.digits.len());
-        let mut repr = Ternary::new(vec![]);
-        let mut carry = Zero;
-        for (i, digit) in self.digits.iter().rev().enumerate() {
-            let d_other = other.get_digit(i).unwrap();
-            let (c, res) = f(*digit, *d_other, carry);
-            carry = c;
-            repr.digits.push(res);
+        modulus.add_digits(&-&reduced)
+    }
+
+    /// Adds two `Ternary` numbers modulo `modulus`.
+    ///
+    /// The sum is computed digit by digit, then reduced into `0..modulus` with the `%` operator.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - A reference to the `Ternary` to add.
+    /// * `modulus` - A reference to a strictly positive `Ternary` modulus.
+    ///
+    /// # Returns
+    ///
+    /// * `Ternary` - The trimmed sum, in `0..modulus`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `modulus` is not strictly positive.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use balanced_ternary::Ternary;
+    ///
+    /// let thirteen = Ternary::from_dec(13);
+    /// let sum = Ternary::from_dec(9).mod_add(&Ternary::from_dec(7), &thirteen);
+    /// assert_eq!(sum.to_dec(), 3);
+    /// ```
+    pub fn mod_add(&self, other: &Ternary, modulus: &Ternary) -> Ternary {
+        self.add_digits(other).modulo(modulus, "mod_add")
+    }
+
+    /// Multiplies two `Ternary` numbers modulo `modulus`.
+    ///
+    /// The product is computed digit by digit, then reduced into `0..modulus` with the `%`
+    /// operator.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - A reference to the `Ternary` to multiply by.
+    /// * `modulus` - A reference to a strictly positive `Ternary` modulus.
+    ///
+    /// # Returns
+    ///
+    /// * `Ternary` - The trimmed product, in `0..modulus`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `modulus` is not strictly positive.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use balanced_ternary::Ternary;
+    ///
+    /// let thirteen = Ternary::from_dec(13);
+    /// let product = Ternary::from_dec(-4).mod_mul(&Ternary::from_dec(5), &thirteen);
+    /// assert_eq!(product.to_dec(), 6);
+    /// ```
+    pub fn mod_mul(&self, other: &Ternary, modulus: &Ternary) -> Ternary {
+        self.mul_digits(other).modulo(modulus, "mod_mul")
+    }
+
+    /// Checks whether two `Ternary` numbers differ by at most `tolerance`.
+    ///
+    /// The difference is computed digit by digit, so this never overflows whatever the lengths
+    /// of the operands.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - A reference to the `Ternary` to compare with.
+    /// * `tolerance` - A reference to the greatest allowed absolute difference.
+    ///
+    /// # Returns
+    ///
+    /// * `bool` - `true` if `abs(self - other) <= tolerance`. Always `false` with a negative
+    ///   `tolerance`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use balanced_ternary::Ternary;
+    ///
+    /// let one = Ternary::parse("+");
+    /// assert!(Ternary::from_dec(41).approx_eq(&Ternary::from_dec(42), &one));
+    /// assert!(!Ternary::from_dec(40).approx_eq(&Ternary::from_dec(42), &one));
+    /// ```
+    pub fn approx_eq(&self, other: &Ternary, tolerance: &Ternary) -> bool {
+        let difference = self.add_digits(&-other);
+        let distance = if digits_sign(&difference.digits) == Neg {
+            -&difference
+        } else {
+            difference
+        };
+        digits_cmp(&distance.digits, &tolerance.digits) != Ordering::Greater
+    }
+
+    /// Converts the `Ternary` number into a sequence of bit pairs, one pair per digit.
+    ///
+    /// Each digit is encoded as `(negative, positive)`:
+    /// - `Neg` is `(true, false)`,
+    /// - `Zero` is `(false, false)`,
+    /// - `Pos` is `(false, true)`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use balanced_ternary::Ternary;
+    ///
+    /// let pairs = Ternary::parse("+0-").to_bit_pairs();
+    /// assert_eq!(pairs, vec![(false, true), (false, false), (true, false)]);
+    /// ```
+    pub fn to_bit_pairs(&self) -> Vec<(bool, bool)> {
+        self.digits
+            .iter()
+            .map(|digit| (*digit == Neg, *digit == Pos))
+            .collect()
+    }
+
+    /// Creates a `Ternary` number from a sequence of bit pairs, as produced by [Ternary::to_bit_pairs].
+    ///
+    /// # Errors
+    ///
+    /// Returns a [ParseTernaryError] if a pair is `(true, true)`, which encodes no digit.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use balanced_ternary::Ternary;
+    ///
+    /// let ternary = Ternary::from_bit_pairs(&[(false, true), (true, false)]).unwrap();
+    /// assert_eq!(ternary.to_string(), "+-");
+    /// assert!(Ternary::from_bit_pairs(&[(true, true)]).is_err());
+    /// ```
+    pub fn from_bit_pairs(pairs: &[(bool, bool)]) -> Result<Ternary, ParseTernaryError> {
+        let mut repr = Ternary::new(vec![]);
+        for pair in pairs {
+            repr.digits.push(match pair {
+                (true, false) => Neg,
+                (false, false) => Zero,
+                (false, true) => Pos,
+                (true, true) => return Err(ParseTernaryError),
+            });
+        }
+        Ok(repr)
+    }
+
+    /// Encodes the digits of the `Ternary` number as runs of equal digits, most significant first.
+    ///
+    /// Each run is a `(digit, count)` pair with `count > 0`, and two consecutive runs never have
+    /// the same digit. Long, mostly `Zero`, numbers are stored into a few runs.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use balanced_ternary::{Digit::{Neg, Pos, Zero}, Ternary};
+    ///
+    /// let runs = Ternary::parse("000++0--").to_rle();
+    /// assert_eq!(runs, vec![(Zero, 3), (Pos, 2), (Zero, 1), (Neg, 2)]);
+    /// ```
+    pub fn to_rle(&self) -> Vec<(Digit, usize)> {
+        let mut runs: Vec<(Digit, usize)> = Vec::new();
+        for digit in self.digits.iter() {
+            match runs.last_mut() {
+                Some((last, count)) if last == digit => *count += 1,
+                _ => runs.push((*digit, 1)),
+            }
+        }
+        runs
+    }
+
+    /// Creates a `Ternary` number from runs of equal digits, as produced by [Ternary::to_rle].
+    ///
+    /// Runs with a count of zero are allowed and ignored.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use balanced_ternary::{Digit::{Neg, Pos, Zero}, Ternary};
+    ///
+    /// let ternary = Ternary::from_rle(&[(Pos, 1), (Zero, 40), (Neg, 1)]);
+    /// assert_eq!(ternary.log(), 42);
+    /// assert_eq!(Ternary::from_rle(&ternary.to_rle()), ternary);
+    /// ```
+    pub fn from_rle(runs: &[(Digit, usize)]) -> Ternary {
+        let length = runs.iter().map(|(_, count)| count).sum();
+        let mut digits = Vec::with_capacity(length);
+        for (digit, count) in runs {
+            digits.extend(core::iter::repeat_n(*digit, *count));
+        }
+        Ternary::new(digits)
+    }
+
+    /// Packs the digits of the `Ternary` number into bytes, 3 bits per digit.
+    ///
+    /// # Format
+    ///
+    /// - A header of 8 bytes: the number of digits, as a little-endian `u64`,
+    /// - then each digit, most significant first, as its [Digit::to_unbalanced] value
+    ///   (`Neg` is 0, `Zero` is 1, `Pos` is 2) on 3 bits. The bits are packed across byte
+    ///   boundaries, from the least significant bit of each byte, and the last byte is
+    ///   padded with `0` bits.
+    ///
+    /// Use [Ternary::from_3bit_packed] to get the `Ternary` back.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use balanced_ternary::Ternary;
+    ///
+    /// let packed = Ternary::parse("+0-").to_3bit_packed();
+    /// assert_eq!(packed, vec![3, 0, 0, 0, 0, 0, 0, 0, 0b00_001_010, 0]);
+    /// ```
+    pub fn to_3bit_packed(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(8 + (self.log() * 3).div_ceil(8));
+        bytes.extend_from_slice(&(self.log() as u64).to_le_bytes());
+        let mut buffer = 0u16;
+        let mut bits = 0;
+        for digit in self.digits.iter() {
+            buffer |= (digit.to_unbalanced() as u16) << bits;
+            bits += 3;
+            if bits >= 8 {
+                bytes.push(buffer as u8);
+                buffer >>= 8;
+                bits -= 8;
+            }
+        }
+        if bits > 0 {
+            bytes.push(buffer as u8);
+        }
+        bytes
+    }
+
+    /// Unpacks the digits packed by [Ternary::to_3bit_packed].
+    ///
+    /// # Errors
+    ///
+    /// Returns a [ParseTernaryError] if the header is missing, if there are not enough bytes
+    /// for the number of digits of the header, or if a 3 bits value is not 0, 1 or 2.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use balanced_ternary::Ternary;
+    ///
+    /// let ternary = Ternary::parse("+0--+0");
+    /// assert_eq!(Ternary::from_3bit_packed(&ternary.to_3bit_packed()), Ok(ternary));
+    /// assert!(Ternary::from_3bit_packed(&[1, 0, 0]).is_err());
+    /// ```
+    pub fn from_3bit_packed(bytes: &[u8]) -> Result<Ternary, ParseTernaryError> {
+        let (header, data) = bytes.split_first_chunk::<8>().ok_or(ParseTernaryError)?;
+        let length = usize::try_from(u64::from_le_bytes(*header)).map_err(|_| ParseTernaryError)?;
+        match length.checked_mul(3) {
+            Some(bits) if bits.div_ceil(8) <= data.len() => {}
+            _ => return Err(ParseTernaryError),
+        }
+        let mut digits = Vec::with_capacity(length);
+        let mut data = data.iter();
+        let mut buffer = 0u16;
+        let mut bits = 0;
+        for _ in 0..length {
+            if bits < 3 {
+                // Enough bytes have been checked above.
+                buffer |= (*data.next().unwrap() as u16) << bits;
+                bits += 8;
+            }
+            match buffer & 0b111 {
+                value @ 0..=2 => digits.push(Digit::from_unbalanced(value as u8)),
+                _ => return Err(ParseTernaryError),
+            }
+            buffer >>= 3;
+            bits -= 3;
+        }
+        Ok(Ternary::new(digits))
+    }
+
+    /// Converts the `Ternary` number into a string, with the glyphs of `glyphs` for each digit.
+    ///
+    /// This is [Ternary::to_string_repr] with a [DigitGlyphs] mapping.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use balanced_ternary::{DigitGlyphs, Ternary};
+    ///
+    /// let ternary = Ternary::parse("+0-");
+    /// assert_eq!(ternary.to_string_with_glyphs(&DigitGlyphs::T), "10T");
+    /// let glyphs = DigitGlyphs { neg: 'n', zero: '_', pos: 'p' };
+    /// assert_eq!(ternary.to_string_with_glyphs(&glyphs), "p_n");
+    /// ```
+    pub fn to_string_with_glyphs(&self, glyphs: &DigitGlyphs) -> String {
+        self.to_string_repr(|digit| glyphs.to_char(*digit))
+    }
+
+    /// Parses a string written with the glyphs of `glyphs`, as produced by
+    /// [Ternary::to_string_with_glyphs].
+    ///
+    /// # Errors
+    ///
+    /// Returns a [ParseTernaryError] if a character is not one of the glyphs.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use balanced_ternary::{DigitGlyphs, Ternary};
+    ///
+    /// let ternary = Ternary::parse_with_glyphs("PZN", &DigitGlyphs::NZP).unwrap();
+    /// assert_eq!(ternary.to_string(), "+0-");
+    /// assert!(Ternary::parse_with_glyphs("+0-", &DigitGlyphs::NZP).is_err());
+    /// ```
+    pub fn parse_with_glyphs(s: &str, glyphs: &DigitGlyphs) -> Result<Ternary, ParseTernaryError> {
+        let digits = s
+            .chars()
+            .map(|c| glyphs.try_from_char(c))
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(Ternary::new(digits))
+    }
+
+    /// Converts the `Ternary` number into a string of Setun tape glyphs.
+    ///
+    /// The glyphs are the ones of [Digit::to_char_setun]:
+    ///
+    /// | Digit  | Glyph |
+    /// |--------|-------|
+    /// | `Neg`  | `○`   |
+    /// | `Zero` | `·`   |
+    /// | `Pos`  | `●`   |
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use balanced_ternary::Ternary;
+    ///
+    /// assert_eq!(Ternary::parse("+0-").to_setun_tape(), "●·○");
+    /// ```
+    pub fn to_setun_tape(&self) -> String {
+        self.to_string_repr(Digit::to_char_setun)
+    }
+
+    /// Parses a string of Setun tape glyphs, as produced by [Ternary::to_setun_tape].
+    ///
+    /// # Errors
+    ///
+    /// Returns a [ParseTernaryError] if a character is not one of `○`, `·` or `●`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use balanced_ternary::Ternary;
+    ///
+    /// let ternary = Ternary::from_setun_tape("●·○").unwrap();
+    /// assert_eq!(ternary.to_string(), "+0-");
+    /// assert!(Ternary::from_setun_tape("●+○").is_err());
+    /// ```
+    pub fn from_setun_tape(tape: &str) -> Result<Ternary, ParseTernaryError> {
+        if !tape.chars().all(|c| matches!(c, '○' | '·' | '●')) {
+            return Err(ParseTernaryError);
+        }
+        Ok(Ternary::new(tape.chars().map(Digit::from_char_setun).collect()))
+    }
+
+    /// Builds a `Ternary` from an iterator of `+`, `0` and `-` characters.
+    ///
+    /// The characters are consumed lazily, so a stream can be read without building a `&str` first.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [ParseTernaryError] at the first invalid character (see [Digit::try_from_char]).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use balanced_ternary::Ternary;
+    ///
+    /// let ternary = Ternary::from_char_iter("+0-".chars()).unwrap();
+    /// assert_eq!(ternary.to_dec(), 8);
+    /// assert!(Ternary::from_char_iter("+x-".chars()).is_err());
+    /// ```
+    pub fn from_char_iter(
+        iter: impl Iterator<Item = char>,
+    ) -> Result<Ternary, ParseTernaryError> {
+        Ok(Ternary::new(
+            iter.map(Digit::try_from_char).collect::<Result<_, _>>()?,
+        ))
+    }
+
+    /// Returns the floor of the base 3 logarithm of the `Ternary` number.
+    ///
+    /// The result is computed from the digits only, without any conversion to a float
+    /// or an integer, so it works for numbers of any length.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(n)` - where `3^n <= self < 3^(n+1)`, if the number is positive.
+    /// * `None` - if the number is zero or negative.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use balanced_ternary::Ternary;
+    ///
+    /// assert_eq!(Ternary::from_dec(26).log3_floor(), Some(2));
+    /// assert_eq!(Ternary::from_dec(27).log3_floor(), Some(3));
+    /// assert_eq!(Ternary::from_dec(0).log3_floor(), None);
+    /// ```
+    pub fn log3_floor(&self) -> Option<usize> {
+        let start = self.digits.iter().position(|d| *d != Zero)?;
+        if self.digits[start] != Pos {
+            return None;
+        }
+        // The value is at least `3^n` unless the digits after the leading `Pos` are negative.
+        let n = self.log() - start - 1;
+        if digits_sign(&self.digits[start + 1..]) == Neg {
+            Some(n - 1)
+        } else {
+            Some(n)
+        }
+    }
+
+    /// Computes the balanced ternary expansion of the rational number `num / den`.
+    ///
+    /// The expansion has `frac_digits` fractional digits: the value `num / den` is approximated
+    /// by `integer + fraction / 3^frac_digits`.
+    ///
+    /// # Rounding
+    ///
+    /// `num * 3^frac_digits / den` is rounded to the nearest integer, ties toward zero
+    /// (so `1/2` gives `0.++...+` rather than `+.--...-`).
+    /// As in every balanced ternary expansion, the integer part is then the nearest integer
+    /// to the value and the fraction may be negative: its value is in `]-1/2, 1/2[`.
+    ///
+    /// # Arguments
+    ///
+    /// * `num` - The numerator.
+    /// * `den` - The denominator.
+    /// * `frac_digits` - The number of fractional digits.
+    ///
+    /// # Returns
+    ///
+    /// * `(Ternary, Ternary)` - The trimmed integer part, and the fractional part of exactly
+    ///   `frac_digits` digits.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `den` is zero, or if `num * 3^frac_digits` overflows an `i128`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use balanced_ternary::Ternary;
+    ///
+    /// let (integer, fraction) = Ternary::from_ratio(1, 3, 4);
+    /// assert_eq!(integer.to_string(), "0");
+    /// assert_eq!(fraction.to_string(), "+000");
+    ///
+    /// let (integer, fraction) = Ternary::from_ratio(7, 2, 2);
+    /// assert_eq!(integer.to_string(), "+0"); // 3
+    /// assert_eq!(fraction.to_string(), "++"); // 4/9
+    /// ```
+    pub fn from_ratio(num: i64, den: i64, frac_digits: usize) -> (Ternary, Ternary) {
+        if den == 0 {
+            panic!("Cannot divide by zero.");
+        }
+        let scaled = 3_i128
+            .checked_pow(frac_digits as u32)
+            .and_then(|power| power.checked_mul(num as i128))
+            .expect("Overflow in Ternary::from_ratio().");
+        let (scaled, den) = if den < 0 {
+            (-scaled, -(den as i128))
+        } else {
+            (scaled, den as i128)
+        };
+        let mut value = scaled / den;
+        if 2 * (scaled % den).abs() > den {
+            value += scaled.signum();
+        }
+
+        let mut digits = vec![];
+        while value != 0 {
+            let digit = (value + 1).rem_euclid(3) - 1;
+            digits.push(Digit::from_i8(digit as i8));
+            value = (value - digit) / 3;
+        }
+        digits.reverse();
+        let repr = Ternary::new(digits).with_length(frac_digits);
+        let split = repr.log() - frac_digits;
+        (
+            Ternary::new(repr.digits[..split].to_vec()).trim(),
+            Ternary::new(repr.digits[split..].to_vec()),
+        )
+    }
+
+    /// Computes the length of the longest carry chain when adding two `Ternary` numbers.
+    ///
+    /// The addition is simulated digit by digit, from the least significant digit, as a ripple
+    /// carry adder would do. A carry chain is a run of consecutive positions receiving a non-`Zero`
+    /// carry, the final carry out included. Useful to estimate the timing of a ternary ALU.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - A reference to the `Ternary` to add.
+    ///
+    /// # Returns
+    ///
+    /// * `usize` - The number of positions of the longest carry chain, `0` if no carry occurs.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use balanced_ternary::ter;
+    ///
+    /// // Each position propagates the carry to the next one, up to the carry out.
+    /// assert_eq!(ter("+++").carry_chain_length(&ter("+++")), 3);
+    /// assert_eq!(ter("+0-").carry_chain_length(&ter("0+0")), 0);
+    /// ```
+    pub fn carry_chain_length(&self, other: &Ternary) -> usize {
+        let mut carry = 0i8;
+        let mut chain = 0;
+        let mut longest = 0;
+        for i in 0..self.log().max(other.log()) {
+            let a = self.get_digit(i).map_or(0, Digit::to_i8);
+            let b = other.get_digit(i).map_or(0, Digit::to_i8);
+            let sum = a + b + carry;
+            let digit = (sum + 1).rem_euclid(3) - 1;
+            carry = (sum - digit) / 3;
+            if carry != 0 {
+                chain += 1;
+                longest = longest.max(chain);
+            } else {
+                chain = 0;
+            }
+        }
+        longest
+    }
+
+    /// Sums all the given `Ternary` numbers, without any length limitation.
+    ///
+    /// The digits of each column are first accumulated, then the carries are propagated
+    /// in a single pass, instead of adding the terms pairwise.
+    ///
+    /// # Arguments
+    ///
+    /// * `terms` - The `Ternary` numbers to add.
+    ///
+    /// # Returns
+    ///
+    /// * `Ternary` - The trimmed sum, `"0"` if `terms` is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use balanced_ternary::{ter, Ternary};
+    ///
+    /// assert_eq!(Ternary::sum_all(&[ter("+"), ter("+"), ter("+")]).to_string(), "+0");
+    /// assert_eq!(Ternary::sum_all(&[ter("+-0"), ter("-"), ter("0++")]).to_dec(), 9);
+    /// ```
+    pub fn sum_all(terms: &[Ternary]) -> Ternary {
+        let length = terms.iter().map(|term| term.log()).max().unwrap_or(0);
+        // Columns, from the least significant digit.
+        let mut columns = vec![0i64; length];
+        for term in terms {
+            for (column, digit) in columns.iter_mut().zip(term.digits.iter().rev()) {
+                *column += digit.to_i8() as i64;
+            }
+        }
+
+        let mut digits = Vec::with_capacity(length + 1);
+        let mut carry = 0i64;
+        let mut i = 0;
+        while i < length || carry != 0 {
+            let sum = columns.get(i).copied().unwrap_or(0) + carry;
+            let digit = (sum + 1).rem_euclid(3) - 1;
+            digits.push(Digit::from_i8(digit as i8));
+            carry = (sum - digit) / 3;
+            i += 1;
+        }
+        digits.reverse();
+        let mut repr = Ternary::new(digits);
+        repr.trim_in_place();
+        repr
+    }
+
+    /// Returns a compact summary of the `Ternary` number, useful to log very large numbers.
+    ///
+    /// Leading zeros are ignored. The summary is made of:
+    /// - the 4 most significant digits, `...`, and the 4 least significant digits
+    ///   (or all the digits if there are at most 8 of them),
+    /// - the power of three of the most significant digit,
+    /// - the total number of digits.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use balanced_ternary::Ternary;
+    ///
+    /// let ternary = Ternary::parse("+-0+00000000000000-0-+");
+    /// assert_eq!(ternary.to_summary_string(), "+-0+...-0-+ ×3^21 (22 trits)");
+    ///
+    /// let ternary = Ternary::parse("00+0-");
+    /// assert_eq!(ternary.to_summary_string(), "+0- ×3^2 (3 trits)");
+    /// ```
+    pub fn to_summary_string(&self) -> String {
+        const SUMMARY_DIGITS: usize = 4;
+        let trimmed = self.trim();
+        let length = trimmed.log();
+        let digits = if length <= 2 * SUMMARY_DIGITS {
+            trimmed.to_string()
+        } else {
+            format!(
+                "{}...{}",
+                Ternary::new(trimmed.digits[..SUMMARY_DIGITS].to_vec()),
+                Ternary::new(trimmed.digits[length - SUMMARY_DIGITS..].to_vec())
+            )
+        };
+        format!(
+            "{} ×3^{} ({} {})",
+            digits,
+            length - 1,
+            length,
+            if length == 1 { "trit" } else { "trits" }
+        )
+    }
+
+    /// Formats the `Ternary` number as a Verilog/VHDL-style sized constant literal.
+    ///
+    /// The syntax is `<width>'<prefix><digits>`, where `width` is the number of digits
+    /// (leading zeros included) and `digits` is the balanced ternary string (`+`, `0`, `-`).
+    ///
+    /// # Arguments
+    ///
+    /// * `prefix` - The base marker written after the `'`, like `b` or `t`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use balanced_ternary::Ternary;
+    ///
+    /// assert_eq!(Ternary::parse("+0-").to_hdl_literal("b"), "3'b+0-");
+    /// assert_eq!(Ternary::parse("00+").to_hdl_literal("t"), "3't00+");
+    /// ```
+    pub fn to_hdl_literal(&self, prefix: &str) -> String {
+        format!("{}'{}{}", self.log().max(1), prefix, self)
+    }
+
+    /// Right-aligns the balanced ternary string of the `Ternary` number in `total_width`
+    /// columns, padded with spaces (not with `Zero` digits). Useful for tabular dumps.
+    ///
+    /// # Arguments
+    ///
+    /// * `total_width` - The number of columns of the result.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the `Ternary` has more than `total_width` digits (an empty `Ternary` is
+    /// displayed as `"0"`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use balanced_ternary::Ternary;
+    ///
+    /// assert_eq!(Ternary::parse("+0-").to_aligned_string(6), "   +0-");
+    /// ```
+    pub fn to_aligned_string(&self, total_width: usize) -> String {
+        if self.log().max(1) > total_width {
+            panic!(
+                "Ternary::to_aligned_string(): Ternary is wider than {} columns: {}",
+                total_width, self
+            );
+        }
+        format!("{:>width$}", self.to_string(), width = total_width)
+    }
+
+    /// Returns the balanced ternary string of the `Ternary` number with `sep` inserted every
+    /// `group` digits from the right, like thousands separators. The most significant group
+    /// may be shorter.
+    ///
+    /// If `group` is `0`, the string is not grouped. As with `Display`, an empty `Ternary` gives
+    /// `"0"`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use balanced_ternary::ter;
+    ///
+    /// assert_eq!(ter("++++0").to_grouped_string(3, '_'), "++_++0");
+    /// assert_eq!(ter("+0-+0-").to_grouped_string(3, ' '), "+0- +0-");
+    /// ```
+    pub fn to_grouped_string(&self, group: usize, sep: char) -> String {
+        let repr = self.to_string();
+        if group == 0 {
+            return repr;
+        }
+        let mut grouped = String::with_capacity(repr.len() + repr.len() / group);
+        for (i, c) in repr.chars().enumerate() {
+            if i > 0 && (repr.len() - i) % group == 0 {
+                grouped.push(sep);
+            }
+            grouped.push(c);
+        }
+        grouped
+    }
+
+    /// Counts the digits of the `Ternary` number by value.
+    ///
+    /// # Returns
+    ///
+    /// * `[usize; 3]` - The numbers of `Neg`, `Zero` and `Pos` digits, in this order
+    ///   (indexed by [Digit::to_unbalanced]).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use balanced_ternary::ter;
+    ///
+    /// assert_eq!(ter("+0-0+").histogram(), [1, 2, 2]);
+    /// ```
+    pub fn histogram(&self) -> [usize; 3] {
+        let mut counts = [0; 3];
+        for digit in self.digits.iter() {
+            counts[digit.to_unbalanced() as usize] += 1;
+        }
+        counts
+    }
+
+    /// Sums the values (`-1`, `0` or `1`) of the digits of the `Ternary` number.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use balanced_ternary::ter;
+    ///
+    /// assert_eq!(ter("+++").digit_sum(), 3);
+    /// assert_eq!(ter("+0--").digit_sum(), -1);
+    /// ```
+    pub fn digit_sum(&self) -> i64 {
+        self.digits.iter().map(|digit| digit.to_i8() as i64).sum()
+    }
+
+    /// Reduces the `Ternary` number to a single digit by repeatedly summing its digits.
+    ///
+    /// The [Ternary::digit_sum] is converted back into balanced ternary and summed again,
+    /// until it fits into one trit. Useful as a small checksum.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use balanced_ternary::{ter, Digit};
+    ///
+    /// // "+++" -> 3 = "+0" -> 1
+    /// assert_eq!(ter("+++").digit_root(), Digit::Pos);
+    /// // "++" -> 2 = "+-" -> 0
+    /// assert_eq!(ter("++").digit_root(), Digit::Zero);
+    /// ```
+    pub fn digit_root(&self) -> Digit {
+        let mut sum = self.digit_sum();
+        while !(-1..=1).contains(&sum) {
+            sum = Ternary::from_dec(sum).digit_sum();
+        }
+        Digit::from_i8(sum as i8)
+    }
+
+    /// Computes the digit-wise maximum of two `Ternary` numbers (`Neg < Zero < Pos`).
+    ///
+    /// Unlike a numeric maximum, each position is compared independently. The shortest
+    /// operand is padded with leading `Zero` digits. This is the same as the `|` operator
+    /// of the Kleene logic.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use balanced_ternary::ter;
+    ///
+    /// assert_eq!(ter("+0-").digit_max(&ter("-0+")).to_string(), "+0+");
+    /// assert_eq!(ter("+0-").digit_max(&ter("-")).to_string(), "+0-");
+    /// ```
+    pub fn digit_max(&self, other: &Ternary) -> Ternary {
+        self.each_zip(
+            |a, b| if a.to_i8() >= b.to_i8() { a } else { b },
+            other.clone(),
+        )
+    }
+
+    /// Computes the digit-wise minimum of two `Ternary` numbers (`Neg < Zero < Pos`).
+    ///
+    /// Unlike a numeric minimum, each position is compared independently. The shortest
+    /// operand is padded with leading `Zero` digits. This is the same as the `&` operator
+    /// of the Kleene logic.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use balanced_ternary::ter;
+    ///
+    /// assert_eq!(ter("+0-").digit_min(&ter("-0+")).to_string(), "-0-");
+    /// assert_eq!(ter("+0+").digit_min(&ter("+")).to_string(), "00+");
+    /// ```
+    pub fn digit_min(&self, other: &Ternary) -> Ternary {
+        self.each_zip(
+            |a, b| if a.to_i8() <= b.to_i8() { a } else { b },
+            other.clone(),
+        )
+    }
+
+    /// Computes the next lexicographic arrangement of the digits of the `Ternary` number.
+    ///
+    /// The digits are treated as symbols ordered `Neg < Zero < Pos`, not as a number: the result
+    /// is made of the same digits, in the next order. Starting from the digits sorted in
+    /// ascending order, repeated calls enumerate every distinct arrangement.
+    ///
+    /// # Returns
+    ///
+    /// * `Option<Ternary>` - The next arrangement, or `None` if the digits are in descending
+    ///   order (the last arrangement).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use balanced_ternary::ter;
+    ///
+    /// assert_eq!(ter("-0+").next_digit_permutation(), Some(ter("-+0")));
+    /// assert_eq!(ter("+0-").next_digit_permutation(), None);
+    /// ```
+    pub fn next_digit_permutation(&self) -> Option<Ternary> {
+        let mut digits = self.digits.clone();
+        // The rightmost digit which is less than its successor.
+        let pivot = digits
+            .windows(2)
+            .rposition(|pair| pair[0].to_i8() < pair[1].to_i8())?;
+        // The rightmost digit greater than the pivot.
+        let successor = digits
+            .iter()
+            .rposition(|digit| digit.to_i8() > digits[pivot].to_i8())?;
+        digits.swap(pivot, successor);
+        digits[pivot + 1..].reverse();
+        Some(Ternary::new(digits))
+    }
+
+    /// Decomposes the `Ternary` number into its sign and its magnitude.
+    ///
+    /// The sign of a balanced ternary number is its most significant non-`Zero` digit,
+    /// and its magnitude is obtained by negating every digit of a negative number.
+    /// It works directly on the digits, for numbers of any length.
+    ///
+    /// # Returns
+    ///
+    /// * `(Digit, Ternary)` - The sign (`Zero` for zero), and the trimmed non-negative magnitude.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use balanced_ternary::{ter, Neg, Zero};
+    ///
+    /// assert_eq!(ter("0-+0").to_sign_magnitude(), (Neg, ter("+-0")));
+    /// assert_eq!(ter("00").to_sign_magnitude(), (Zero, ter("0")));
+    /// ```
+    pub fn to_sign_magnitude(&self) -> (Digit, Ternary) {
+        let sign = digits_sign(&self.digits);
+        let magnitude = self.trim();
+        if sign == Neg {
+            (sign, -&magnitude)
+        } else {
+            (sign, magnitude)
+        }
+    }
+
+    /// Converts the `Ternary` number into its balanced ternary Gray code.
+    ///
+    /// The variant used is a reflected Gray code: reading from the most significant digit,
+    /// each trit is negated when an odd number of non-`Zero` trits precede it.
+    /// Two successive integers then always differ by exactly one trit (once aligned to the
+    /// same length), and leading zeros do not change the code of a number.
+    ///
+    /// As negating a trit keeps it non-`Zero`, this transformation is its own inverse.
+    ///
+    /// # Returns
+    ///
+    /// * `Ternary` - The Gray code, with the same number of digits.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use balanced_ternary::ter;
+    ///
+    /// assert_eq!(ter("+-").to_gray(), ter("++")); // 2
+    /// assert_eq!(ter("+0").to_gray(), ter("+0")); // 3
+    /// assert_eq!(ter("++").to_gray(), ter("+-")); // 4
+    /// ```
+    pub fn to_gray(&self) -> Ternary {
+        let mut odd = false;
+        let digits = self
+            .digits
+            .iter()
+            .map(|&digit| {
+                let code = if odd { -digit } else { digit };
+                if digit != Zero {
+                    odd = !odd;
+                }
+                code
+            })
+            .collect();
+        Ternary::new(digits)
+    }
+
+    /// Converts a balanced ternary Gray code (see [Ternary::to_gray]) back into its number.
+    ///
+    /// # Returns
+    ///
+    /// * `Ternary` - The decoded number, with the same number of digits.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use balanced_ternary::ter;
+    ///
+    /// assert_eq!(ter("++").from_gray(), ter("+-"));
+    /// ```
+    pub fn from_gray(&self) -> Ternary {
+        self.to_gray()
+    }
+
+    /// Evaluates the digits as the coefficients of a polynomial at the given base.
+    ///
+    /// This computes `sum(digit * base^rank)` (with Horner's method), so `eval_at(3)` is
+    /// the same as [Ternary::to_dec], without the risk of overflowing.
+    ///
+    /// # Arguments
+    ///
+    /// * `base` - The value at which the polynomial is evaluated.
+    ///
+    /// # Returns
+    ///
+    /// * `Option<i64>` - The value, or `None` if it does not fit into an `i64`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use balanced_ternary::ter;
+    ///
+    /// assert_eq!(ter("+0-").eval_at(10), Some(99));
+    /// assert_eq!(ter("+0-").eval_at(3), Some(8));
+    /// assert_eq!(ter("+0-").eval_at(i64::MAX), None);
+    /// ```
+    pub fn eval_at(&self, base: i64) -> Option<i64> {
+        // The intermediate results may exceed the range of the (in range) final value.
+        let value = self.digits.iter().try_fold(0i128, |acc, digit| {
+            acc.checked_mul(base as i128)?.checked_add(digit.to_i8() as i128)
+        })?;
+        i64::try_from(value).ok()
+    }
+
+    /// Multiplies the `Ternary` number by `3^n` (a left shift of `n` digits), checking the length of the result.
+    ///
+    /// # Arguments
+    ///
+    /// * `n` - The power of 3 to multiply by.
+    /// * `max_len` - The maximum number of digits allowed for the result (the width of a `Tryte`, for example).
+    ///
+    /// # Returns
+    ///
+    /// * `Some(Ternary)` - The trimmed product, if it has at most `max_len` digits.
+    /// * `None` - If the product needs more than `max_len` digits.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use balanced_ternary::ter;
+    ///
+    /// assert_eq!(ter("0+-").checked_mul_pow3(2, 4), Some(ter("+-00")));
+    /// assert_eq!(ter("0+-").checked_mul_pow3(3, 4), None);
+    /// ```
+    pub fn checked_mul_pow3(&self, n: usize, max_len: usize) -> Option<Ternary> {
+        let trimmed = self.trim();
+        let product = if digits_sign(&trimmed.digits) == Zero {
+            trimmed
+        } else {
+            &trimmed << n
+        };
+        if product.log() > max_len {
+            None
+        } else {
+            Some(product)
+        }
+    }
+
+    /// Applies a transformation function to each digit, like [DigitOperate::each], and tells
+    /// whether any digit was changed.
+    ///
+    /// Useful to iterate an operator until the value becomes stable.
+    ///
+    /// # Arguments
+    ///
+    /// * `f` - A closure or function that takes a `Digit` and returns a transformed `Digit`.
+    ///
+    /// # Returns
+    ///
+    /// * `(Ternary, bool)` - The transformed ternary, and `true` if at least one digit differs.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use balanced_ternary::{ter, Digit};
+    ///
+    /// let mut ternary = ter("+0-");
+    /// loop {
+    ///     let (next, changed) = ternary.each_tracked(Digit::possibly);
+    ///     ternary = next;
+    ///     if !changed {
+    ///         break;
+    ///     }
+    /// }
+    /// assert_eq!(ternary.to_string(), "++-");
+    /// ```
+    pub fn each_tracked(&self, f: impl Fn(Digit) -> Digit) -> (Ternary, bool) {
+        let mut changed = false;
+        let digits = self
+            .digits
+            .iter()
+            .map(|&digit| {
+                let result = f(digit);
+                changed |= result != digit;
+                result
+            })
+            .collect();
+        (Ternary::new(digits), changed)
+    }
+
+    /// Adds two `Ternary` numbers digit by digit, without any length limitation.
+    fn add_digits(&self, other: &Ternary) -> Ternary {
+        let length = self.log().max(other.log()) + 1;
+        self.with_length(length)
+            .each_zip_carry(
+                |a, b, carry| {
+                    let sum = a.to_i8() + b.to_i8() + carry.to_i8();
+                    let digit = (sum + 1).rem_euclid(3) - 1;
+                    (Digit::from_i8((sum - digit) / 3), Digit::from_i8(digit))
+                },
+                other.clone(),
+            )
+            .trim()
+    }
+
+    /// Multiplies two `Ternary` numbers digit by digit, without any length limitation.
+    fn mul_digits(&self, other: &Ternary) -> Ternary {
+        let mut product = Ternary::parse("0");
+        for (shift, digit) in other.digits.iter().rev().enumerate() {
+            let partial = match digit {
+                Neg => -self,
+                Zero => continue,
+                Pos => self.clone(),
+            };
+            product = product.add_digits(&(&partial << shift));
+        }
+        product
+    }
+
+    /// Reduces the `Ternary` number into the range `0..modulus`.
+    ///
+    /// `method` is the name of the public method, used in the panic message.
+    fn modulo(&self, modulus: &Ternary, method: &str) -> Ternary {
+        if digits_sign(&modulus.digits) != Pos {
+            panic!("Ternary::{}(): Invalid modulus: {}", method, modulus);
+        }
+        let remainder = self % modulus;
+        if digits_sign(&remainder.digits) == Neg {
+            remainder.add_digits(modulus)
+        } else {
+            remainder
+        }
+    }
+}
+
+/// Returns the sign of a sequence of digits, which is its most significant non-`Zero` digit.
+#[cfg(feature = "ternary-string")]
+fn digits_sign(digits: &[Digit]) -> Digit {
+    digits.iter().find(|d| **d != Zero).cloned().unwrap_or(Zero)
+}
+
+/// Compares the values of two sequences of digits, whatever their lengths.
+#[cfg(feature = "ternary-string")]
+fn digits_cmp(a: &[Digit], b: &[Digit]) -> Ordering {
+    let length = a.len().max(b.len());
+    let padded = |digits: &[Digit], i: usize| -> i8 {
+        let offset = length - digits.len();
+        if i < offset {
+            0
+        } else {
+            digits[i - offset].to_i8()
+        }
+    };
+    for i in 0..length {
+        match padded(a, i).cmp(&padded(b, i)) {
+            Ordering::Equal => continue,
+            ordering => return ordering,
+        }
+    }
+    Ordering::Equal
+}
+
+#[cfg(feature = "ternary-string")]
+impl DigitOperate for Ternary {
+    fn to_digits(&self) -> Vec<Digit> {
+        self.to_digit_slice().to_vec()
+    }
+
+    fn digit(&self, index: usize) -> Option<Digit> {
+        self.get_digit(index).cloned()
+    }
+
+    fn each(&self, f: impl Fn(Digit) -> Digit) -> Self {
+        let mut repr = Ternary::new(vec![]);
+        for digit in self.digits.iter() {
+            repr.digits.push(f(*digit));
+        }
+        repr
+    }
+
+    fn each_with(&self, f: impl Fn(Digit, Digit) -> Digit, other: Digit) -> Self {
+        let mut repr = Ternary::new(vec![]);
+        for digit in self.digits.iter() {
+            repr.digits.push(f(*digit, other));
+        }
+        repr
+    }
+
+    fn each_zip(&self, f: impl Fn(Digit, Digit) -> Digit, other: Self) -> Self {
+        // The longest operand is always the first argument of `f`,
+        // the shortest one is virtually padded with leading `Zero`s.
+        let (long, short) = if self.digits.len() < other.digits.len() {
+            (&other, self)
+        } else {
+            (self, &other)
+        };
+        let offset = long.digits.len() - short.digits.len();
+        let digits = long
+            .digits
+            .iter()
+            .enumerate()
+            .map(|(i, digit)| {
+                let d_other = if i < offset { Zero } else { short.digits[i - offset] };
+                f(*digit, d_other)
+            })
+            .collect();
+        Ternary::new(digits)
+    }
+
+    fn each_zip_carry(
+        &self,
+        f: impl Fn(Digit, Digit, Digit) -> (Digit, Digit),
+        other: Self,
+    ) -> Self {
+        // As in `each_zip`, the longest operand is always the first argument of `f`.
+        let (long, short) = if self.digits.len() < other.digits.len() {
+            (&other, self)
+        } else {
+            (self, &other)
+        };
+        let offset = long.digits.len() - short.digits.len();
+        let mut digits = vec![Zero; long.digits.len()];
+        let mut carry = Zero;
+        for (i, digit) in long.digits.iter().enumerate().rev() {
+            let d_other = if i < offset { Zero } else { short.digits[i - offset] };
+            let (c, res) = f(*digit, d_other, carry);
+            carry = c;
+            digits[i] = res;
+        }
+        Ternary::new(digits)
+    }
+}
+
+#[cfg(feature = "ternary-string")]
+impl Display for Ternary {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        self.write_to(f)
+    }
+}
+
+#[cfg(feature = "ternary-string")]
+impl FromStr for Ternary {
+    type Err = ParseTernaryError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.chars().all(|c| matches!(c, '+' | '0' | '-')) {
+            Ok(Ternary::parse(s))
+        } else {
+            Err(ParseTernaryError)
+        }
+    }
+}
+
+/// A stateful parser building a [Ternary] from successive string chunks.
+///
+/// Useful when the balanced ternary text arrives in partial buffers (from a socket, a file, ...).
+///
+/// # Example
+/// ```
+/// use balanced_ternary::TernaryParser;
+///
+/// let mut parser = TernaryParser::new();
+/// parser.push_str("+0").unwrap();
+/// parser.push_str("-+").unwrap();
+/// assert_eq!(parser.finish().to_string(), "+0-+");
+/// ```
+#[derive(Debug, Clone, Default)]
+#[cfg(feature = "ternary-string")]
+pub struct TernaryParser {
+    digits: Vec<Digit>,
+}
+
+#[cfg(feature = "ternary-string")]
+impl TernaryParser {
+    /// Creates a new empty `TernaryParser`.
+    pub fn new() -> Self {
+        Self { digits: vec![] }
+    }
+
+    /// Appends the digits of a chunk of balanced ternary text to the parser.
+    ///
+    /// Each character of the chunk must be one of `+`, `0`, or `-`.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [ParseTernaryError] at the first invalid character. In this case,
+    /// no digit of the chunk is appended and the parser keeps its previous state.
+    pub fn push_str(&mut self, s: &str) -> Result<(), ParseTernaryError> {
+        if !s.chars().all(|c| matches!(c, '+' | '0' | '-')) {
+            return Err(ParseTernaryError);
+        }
+        self.digits.extend(s.chars().map(Digit::from_char));
+        Ok(())
+    }
+
+    /// Consumes the parser and returns the `Ternary` made of all the accumulated digits.
+    pub fn finish(self) -> Ternary {
+        Ternary::new(self.digits)
+    }
+}
+
+#[cfg(feature = "ternary-string")]
+impl Ord for Ternary {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.to_dec().cmp(&other.to_dec())
+    }
+}
+
+#[cfg(feature = "ternary-string")]
+impl PartialOrd for Ternary {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Compares the value of a `Ternary` with an integer.
+///
+/// The comparison is done digit by digit, so it is correct for ternaries of any length.
+/// Leading zeros are not significant: `ter("00+") == 1`.
+#[cfg(feature = "ternary-string")]
+impl PartialEq<i64> for Ternary {
+    fn eq(&self, other: &i64) -> bool {
+        self.partial_cmp(other) == Some(Ordering::Equal)
+    }
+}
+
+#[cfg(feature = "ternary-string")]
+impl PartialOrd<i64> for Ternary {
+    fn partial_cmp(&self, other: &i64) -> Option<Ordering> {
+        Some(digits_cmp(&self.digits, &Ternary::from_dec(*other).digits))
+    }
+}
+
+#[cfg(feature = "ternary-string")]
+impl PartialEq<Ternary> for i64 {
+    fn eq(&self, other: &Ternary) -> bool {
+        other == self
+    }
+}
+
+#[cfg(feature = "ternary-string")]
+impl PartialOrd<Ternary> for i64 {
+    fn partial_cmp(&self, other: &Ternary) -> Option<Ordering> {
+        other.partial_cmp(self).map(Ordering::reverse)
+    }
+}
+
+#[cfg(feature = "ternary-string")]
+impl IntoIterator for Ternary {
+    type Item = Digit;
+    type IntoIter = alloc::vec::IntoIter<Digit>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.digits.into_iter()
+    }
+}
+
+/// Appends digits in place on the least significant side, in the order of the iterator.
+///
+/// `a.extend(&b)` gives the same digits as `a.concat(&b)`.
+///
+/// # Example
+///
+/// ```
+/// use balanced_ternary::{ter, Neg, Pos};
+///
+/// let mut ternary = ter("+0");
+/// ternary.extend([Neg, Pos]);
+/// assert_eq!(ternary.to_string(), "+0-+");
+/// ternary.extend(&ter("0-"));
+/// assert_eq!(ternary.to_string(), "+0-+0-");
+/// ```
+#[cfg(feature = "ternary-string")]
+impl Extend<Digit> for Ternary {
+    fn extend<T: IntoIterator<Item = Digit>>(&mut self, iter: T) {
+        self.digits.extend(iter);
+    }
+}
+
+#[cfg(feature = "ternary-string")]
+impl<'a> Extend<&'a Digit> for Ternary {
+    fn extend<T: IntoIterator<Item = &'a Digit>>(&mut self, iter: T) {
+        self.digits.extend(iter.into_iter().copied());
+    }
+}
+
+#[cfg(feature = "ternary-string")]
+impl<'a> IntoIterator for &'a Ternary {
+    type Item = &'a Digit;
+    type IntoIter = core::slice::Iter<'a, Digit>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// Returns the [Digit] at `index`, **from the right** like [Ternary::get_digit].
+///
+/// # Panics
+///
+/// Panics if `index` is greater than or equal to [Ternary::log]. Use [Ternary::get_digit]
+/// for a non-panicking access.
+///
+/// # Example
+///
+/// ```
+/// use balanced_ternary::{ter, Digit::{Neg, Pos}};
+///
+/// let ternary = ter("+++--+");
+/// assert_eq!(ternary[0], Pos);
+/// assert_eq!(ternary[1], Neg);
+/// ```
+#[cfg(feature = "ternary-string")]
+impl core::ops::Index<usize> for Ternary {
+    type Output = Digit;
+
+    fn index(&self, index: usize) -> &Self::Output {
+        self.get_digit(index).unwrap_or_else(|| {
+            panic!(
+                "Ternary::index(): the index is {} but the length is {}.",
+                index,
+                self.log()
+            )
+        })
+    }
+}
+
+#[cfg(feature = "ternary-string")]
+mod operations;
+
+mod conversions;
+
+#[cfg(feature = "ternary-string")]
+mod fixed;
+
+#[cfg(feature = "ternary-string")]
+pub use crate::fixed::FixedTernary;
+
+#[cfg(feature = "ternary-store")]
+mod store;
+
+#[cfg(feature = "ternary-store")]
+pub use crate::store::{Ter40, Ter80, DataTernary, StoreError, TritsChunk};
+
+#[cfg(feature = "tryte")]
+mod tryte;
+
+#[cfg(feature = "tryte")]
+pub use crate::tryte::{Tryte, TryteError, TryteFromTernaryError, TryteLengthError};
+
+#[cfg(test)]
+#[cfg(feature = "ternary-string")]
+#[test]
+fn test_ternary() {
+    use crate::*;
+
+    let repr5 = Ternary::new(vec![Pos, Neg, Neg]);
+    assert_eq!(repr5.to_dec(), 5);
+    let repr5 = Ternary::from_dec(5);
+    assert_eq!(repr5.to_dec(), 5);
+
+    let repr13 = Ternary::new(vec![Pos, Pos, Pos]);
+    assert_eq!(repr13.to_dec(), 13);
+
+    let repr14 = Ternary::parse("+---");
+    let repr15 = Ternary::parse("+--0");
+    assert_eq!(repr14.to_dec(), 14);
+    assert_eq!(repr15.to_dec(), 15);
+    assert_eq!(repr14.to_string(), "+---");
+    assert_eq!(repr15.to_string(), "+--0");
+
+    let repr120 = Ternary::from_dec(120);
+    assert_eq!(repr120.to_dec(), 120);
+    assert_eq!(repr120.to_string(), "++++0");
+    let repr121 = Ternary::from_dec(121);
+    assert_eq!(repr121.to_dec(), 121);
+    assert_eq!(repr121.to_string(), "+++++");
+
+    let repr_neg_5 = Ternary::parse("-++");
+    assert_eq!(repr_neg_5.to_dec(), -5);
+    assert_eq!(repr_neg_5.to_string(), "-++");
+
+    let repr_neg_5 = Ternary::from_dec(-5);
+    assert_eq!(repr_neg_5.to_dec(), -5);
+    assert_eq!(repr_neg_5.to_string(), "-++");
+
+    let repr_neg_121 = Ternary::from_dec(-121);
+    assert_eq!(repr_neg_121.to_dec(), -121);
+    assert_eq!(repr_neg_121.to_string(), "-----");
+
+    let test = Ternary::from_dec(18887455);
+    assert_eq!(test.to_dec(), 18887455);
+    assert_eq!(test.to_string(), "++00--0--+-0++0+");
+
+    let unbalanced = Ternary::from_unbalanced("12");
+    assert_eq!(unbalanced.to_dec(), 5);
+    assert_eq!(unbalanced.to_string(), "+--");
+
+    let unbalanced = Ternary::from_unbalanced("-12");
+    assert_eq!(unbalanced.to_dec(), -5);
+    assert_eq!(unbalanced.to_string(), "-++");
+
+    let unbalanced = Ternary::from_dec(121);
+    assert_eq!(unbalanced.to_unbalanced(), "11111");
+    assert_eq!(unbalanced.to_string(), "+++++");
+}
+
+#[cfg(test)]
+#[cfg(feature = "ternary-string")]
+#[test]
+fn test_each() {
+    use crate::*;
+    let ternary = Ternary::parse("+0-");
+    assert_eq!(ternary.each(Digit::possibly).to_string(), "++-");
+}
+
+#[cfg(test)]
+#[cfg(feature = "ternary-string")]
+#[test]
+fn test_operations() {
+    fn test_ternary_eq(a: Ternary, b: &str) {
+        let repr = Ternary::parse(b);
+        assert_eq!(a.to_string(), repr.to_string());
+    }
+    fn test_binary_op(a: &Ternary, f: impl Fn(Digit, Digit) -> Digit, b: &Ternary, c: &str) {
+        test_ternary_eq(a.each_zip(f, b.clone()), c);
+    }
+
+    use core::ops::{BitAnd, BitOr, BitXor, Mul, Not};
+
+    let short = Ternary::parse("-0+");
+    let long = Ternary::parse("---000+++");
+    let other = Ternary::parse("-0+-0+-0+");
+
+    // K3
+    test_ternary_eq(short.each(Digit::not), "+0-");
+    test_binary_op(&long, Digit::bitand, &other, "----00-0+");
+    test_binary_op(&long, Digit::bitor, &other, "-0+00++++");
+    test_binary_op(&long, Digit::bitxor, &other, "-0+000+0-");
+    test_binary_op(&long, Digit::k3_equiv, &other, "+0-000-0+");
+    test_binary_op(&long, Digit::k3_imply, &other, "+++00+-0+");
+
+    // HT
+    test_ternary_eq(short.each(Digit::ht_not), "+--");
+    test_binary_op(&long, Digit::ht_imply, &other, "+++-++-0+");
+
+    // BI3
+    test_binary_op(&long, Digit::bi3_and, &other, "-0-000-0+");
+    test_binary_op(&long, Digit::bi3_or, &other, "-0+000+0+");
+    test_binary_op(&long, Digit::bi3_imply, &other, "+0+000-0+");
+
+    // L3
+    test_ternary_eq(short.each(Digit::possibly), "-++");
+    test_ternary_eq(short.each(Digit::necessary), "--+");
+    test_ternary_eq(short.each(Digit::contingently), "-+-");
+    test_binary_op(&long, Digit::l3_imply, &other, "+++0++-0+");
+
+    // PARA / RM3
+    test_binary_op(&long, Digit::rm3_imply, &other, "+++-0+--+");
+    test_binary_op(&long, Digit::para_imply, &other, "+++-0+-0+");
+
+    // Other operations
+    test_ternary_eq(short.each(Digit::post), "0+-");
+    test_ternary_eq(short.each(Digit::pre), "+-0");
+    test_ternary_eq(short.each(Digit::absolute_positive), "+0+");
+    test_ternary_eq(short.each(Digit::positive), "00+");
+    test_ternary_eq(short.each(Digit::not_negative), "0++");
+    test_ternary_eq(short.each(Digit::not_positive), "--0");
+    test_ternary_eq(short.each(Digit::negative), "-00");
+    test_ternary_eq(short.each(Digit::absolute_negative), "-0-");
+
+    test_binary_op(&long, Digit::mul, &other, "+0-000-0+");
+}
+
+#[cfg(test)]
+#[cfg(feature = "ternary-string")]
+#[test]
+fn test_from_str() {
+    use core::str::FromStr;
+
+    let ternary = Ternary::from_str("+-0").unwrap();
+    assert_eq!(ternary.to_string(), "+-0");
+
+    assert!(Ternary::from_str("+-x").is_err());
+
+    #[cfg(feature = "tryte")]
+    {
+        let tryte = <crate::Tryte>::from_str("+-0").unwrap();
+        assert_eq!(tryte.to_string(), "000+-0");
+        assert!(<crate::Tryte>::from_str("+-x").is_err());
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "ternary-string")]
+#[test]
+fn test_ordering() {
+    use crate::ter;
+
+    assert!(ter("-+") < ter("0"));
+    assert!(ter("0") < ter("++"));
+}
+
+#[cfg(test)]
+#[cfg(feature = "ternary-string")]
+#[test]
+fn test_ordering_additional() {
+    use crate::ter;
+
+    // Validate comparisons across a range of values
+    assert!(ter("--") < ter("-0"));
+    assert!(ter("-0") < ter("-"));
+    assert!(ter("+") < ter("+-"));
+    assert!(ter("+-") < ter("++"));
+
+    // Sorting should arrange values by their decimal value
+    let mut values = vec![ter("+"), ter("--"), ter("+-"), ter("-"), ter("0"), ter("-0"), ter("++")];
+    values.sort();
+    let expected = vec![ter("--"), ter("-0"), ter("-"), ter("0"), ter("+"), ter("+-"), ter("++")];
+    assert_eq!(values, expected);
+}
+
+#[cfg(test)]
+#[cfg(feature = "ternary-string")]
+#[test]
+fn test_iterators() {
+    use crate::*;
+
+    let ternary = Ternary::parse("+0-");
+    let expected = vec![Pos, Zero, Neg];
+    assert_eq!(ternary.iter().cloned().collect::<Vec<_>>(), expected);
+    let collected: Vec<Digit> = Ternary::parse("+0-").into_iter().collect();
+    assert_eq!(collected, expected);
+}
+
+#[cfg(test)]
+#[cfg(feature = "ternary-string")]
+#[test]
+fn test_interleave() {
+    use crate::ter;
+
+    let a = ter("+0-+");
+    let b = ter("-0+-");
+    let interleaved = a.interleave(&b);
+    assert_eq!(interleaved.to_string(), "+-00-++-");
+    assert_eq!(interleaved.deinterleave(), (a, b));
+
+    // Shorter operands are padded with leading zeros.
+    let interleaved = ter("+").interleave(&ter("--"));
+    assert_eq!(interleaved.to_string(), "0-+-");
+    assert_eq!(interleaved.deinterleave(), (ter("0+"), ter("--")));
+
+    // Odd lengths leave the extra digit in the even part.
+    let (even, odd) = ter("+-0++").deinterleave();
+    assert_eq!(even.to_string(), "+0+");
+    assert_eq!(odd.to_string(), "-+");
+    let (even, odd) = ter("-").deinterleave();
+    assert_eq!(even.to_string(), "-");
+    assert_eq!(odd.log(), 0);
+}
+
+#[cfg(test)]
+#[cfg(feature = "ternary-string")]
+#[test]
+fn test_ternary_parser() {
+    use crate::*;
+
+    let mut parser = TernaryParser::new();
+    assert_eq!(parser.push_str("+0"), Ok(()));
+    assert_eq!(parser.push_str("-+"), Ok(()));
+    assert_eq!(parser.finish().to_string(), "+0-+");
+
+    // An invalid chunk is rejected without altering the accumulated digits.
+    let mut parser = TernaryParser::new();
+    parser.push_str("+-").unwrap();
+    assert_eq!(parser.push_str("0x+"), Err(ParseTernaryError));
+    parser.push_str("").unwrap();
+    parser.push_str("0").unwrap();
+    assert_eq!(parser.finish().to_string(), "+-0");
+
+    assert_eq!(TernaryParser::new().finish().log(), 0);
+}
+
+#[cfg(test)]
+#[cfg(feature = "ternary-string")]
+#[test]
+fn test_long_div() {
+    use crate::*;
+
+    let dividend = ter("+-0+-0+0--+0++-0-0+-+0+-0-++0+");
+    let divisor = ter("-+0+-+0-+-");
+    assert_eq!(dividend.log(), 30);
+    assert_eq!(divisor.log(), 10);
+    let (q, r) = dividend.long_div(&divisor);
+    assert_eq!(&(&q * &divisor) + &r, dividend);
+    assert_eq!(q.to_dec(), dividend.to_dec() / divisor.to_dec());
+    assert_eq!(r.to_dec(), dividend.to_dec() % divisor.to_dec());
+
+    // Signs follow the truncated division of Rust integers.
+    for a in -40..=40 {
+        for b in [-7, -3, -2, -1, 1, 2, 5, 9] {
+            let (q, r) = Ternary::from_dec(a).long_div(&Ternary::from_dec(b));
+            assert_eq!((q.to_dec(), r.to_dec()), (a / b, a % b));
+        }
+    }
+
+    // Operands beyond the range of i64.
+    let big = Ternary::parse("+").concat(&Ternary::new(vec![Zero; 59]));
+    let (q, r) = big.long_div(&ter("+000000000"));
+    assert_eq!(q, Ternary::parse("+").concat(&Ternary::new(vec![Zero; 50])));
+    assert_eq!(r.to_string(), "0");
+    let (q, r) = (&big << 1).long_div(&big);
+    assert_eq!(q.to_string(), "+0");
+    assert_eq!(r.to_string(), "0");
+}
+
+#[cfg(test)]
+#[cfg(feature = "ternary-string")]
+#[test]
+#[should_panic(expected = "Cannot divide by zero.")]
+fn test_long_div_by_zero() {
+    crate::ter("+-").long_div(&crate::ter("00"));
+}
+
+#[cfg(test)]
+#[cfg(feature = "ternary-string")]
+#[test]
+fn test_fixed_width_wrapping() {
+    use crate::*;
+
+    assert_eq!(Ternary::from_dec(14).to_fixed_width_wrapping(2).to_dec(), -4);
+    assert_eq!(Ternary::from_dec(-14).to_fixed_width_wrapping(2).to_dec(), 4);
+    assert_eq!(Ternary::from_dec(4).to_fixed_width_wrapping(2).to_string(), "++");
+    assert_eq!(Ternary::from_dec(5).to_fixed_width_wrapping(0).log(), 0);
+
+    for value in -100..=100 {
+        let wrapped = Ternary::from_dec(value).to_fixed_width_wrapping(3);
+        assert_eq!(wrapped.log(), 3);
+        assert!((-13..=13).contains(&wrapped.to_dec()));
+        assert_eq!((value - wrapped.to_dec()).rem_euclid(27), 0);
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "ternary-string")]
+#[test]
+fn test_bit_pairs() {
+    use crate::*;
+
+    let ternary = ter("+-0-+0");
+    let pairs = ternary.to_bit_pairs();
+    assert_eq!(pairs.len(), 6);
+    assert_eq!(pairs[0], (false, true));
+    assert_eq!(pairs[1], (true, false));
+    assert_eq!(pairs[2], (false, false));
+    assert_eq!(Ternary::from_bit_pairs(&pairs), Ok(ternary));
+
+    assert_eq!(Ternary::from_bit_pairs(&[]), Ok(Ternary::new(vec![])));
+    assert_eq!(
+        Ternary::from_bit_pairs(&[(false, true), (true, true)]),
+        Err(ParseTernaryError)
+    );
+}
+
+#[cfg(test)]
+#[cfg(feature = "ternary-string")]
+#[test]
+fn test_setun_tape() {
+    use crate::*;
+
+    let ternary = ter("+-00-+");
+    let tape = ternary.to_setun_tape();
+    assert_eq!(tape, "●○··○●");
+    assert_eq!(Ternary::from_setun_tape(&tape), Ok(ternary));
+
+    for value in -50..=50 {
+        let ternary = Ternary::from_dec(value);
+        assert_eq!(Ternary::from_setun_tape(&ternary.to_setun_tape()), Ok(ternary));
+    }
+
+    assert_eq!(Ternary::from_setun_tape("+0-"), Err(ParseTernaryError));
+    assert_eq!(Digit::from_char_setun('·'), Zero);
+    assert_eq!(Pos.to_char_setun(), '●');
+}
+
+#[cfg(test)]
+#[cfg(feature = "ternary-string")]
+#[test]
+fn test_log3_floor() {
+    use crate::*;
+
+    assert_eq!(Ternary::from_dec(26).log3_floor(), Some(2));
+    assert_eq!(Ternary::from_dec(27).log3_floor(), Some(3));
+    assert_eq!(ter("00+").log3_floor(), Some(0));
+    assert_eq!(ter("+-").log3_floor(), Some(0));
+    assert_eq!(ter("0").log3_floor(), None);
+    assert_eq!(ter("").log3_floor(), None);
+    assert_eq!(ter("-+").log3_floor(), None);
+
+    let mut power = 1;
+    let mut expected = 0;
+    for value in 1..=1000 {
+        if value >= power * 3 {
+            power *= 3;
+            expected += 1;
+        }
+        assert_eq!(Ternary::from_dec(value).log3_floor(), Some(expected));
+    }
+
+    let big = ter("+").concat(&Ternary::new(vec![Zero; 80]));
+    assert_eq!(big.log3_floor(), Some(80));
+}
+
+#[cfg(test)]
+#[cfg(feature = "ternary-string")]
+#[test]
+fn test_compare_with_i64() {
+    use crate::*;
+
+    assert!(ter("+--") == 5);
+    assert!(5 == ter("+--"));
+    assert!(ter("00+--") == 5);
+    assert!(ter("+--") != 4);
+    assert!(ter("+--") < 6);
+    assert!(ter("+--") > -5);
+    assert!(ter("+--") >= 5);
+    assert!(6 > ter("+--"));
+    assert!(-6 < ter("-++"));
+    assert!(ter("") == 0);
+
+    assert!(Ternary::from_dec(i64::MIN) == i64::MIN);
+    assert!(Ternary::from_dec(i64::MAX) == i64::MAX);
+    assert!(Ternary::from_dec(i64::MAX) > i64::MAX - 1);
+
+    // Ternaries beyond the range of i64.
+    let big = ter("+").concat(&Ternary::new(vec![Zero; 60]));
+    assert!(big > i64::MAX);
+    assert!(-&big < i64::MIN);
+    assert!(i64::MAX < big);
+}
+
+#[cfg(test)]
+#[cfg(feature = "ternary-string")]
+#[test]
+fn test_summary_string() {
+    use crate::*;
+
+    let big = ter("-+0+")
+        .concat(&Ternary::new(vec![Zero; 52]))
+        .concat(&ter("+--0"));
+    assert_eq!(big.log(), 60);
+    assert_eq!(big.to_summary_string(), "-+0+...+--0 ×3^59 (60 trits)");
+
+    assert_eq!(ter("+-0+-0+-").to_summary_string(), "+-0+-0+- ×3^7 (8 trits)");
+    assert_eq!(ter("+").to_summary_string(), "+ ×3^0 (1 trit)");
+    assert_eq!(ter("000").to_summary_string(), "0 ×3^0 (1 trit)");
+}
+
+#[cfg(test)]
+#[cfg(feature = "ternary-string")]
+#[test]
+fn test_from_ratio() {
+    use crate::*;
+
+    // 1/3 = 0.+
+    assert_eq!(Ternary::from_ratio(1, 3, 3), (ter("0"), ter("+00")));
+    assert_eq!(Ternary::from_ratio(-1, 3, 3), (ter("0"), ter("-00")));
+    // 1/2 = 0.+++...
+    assert_eq!(Ternary::from_ratio(1, 2, 5), (ter("0"), ter("+++++")));
+    assert_eq!(Ternary::from_ratio(1, -2, 5), (ter("0"), ter("-----")));
+    // 2/3 = +.-
+    assert_eq!(Ternary::from_ratio(2, 3, 2), (ter("+"), ter("-0")));
+    // 1/4 = 0.+-+-...
+    assert_eq!(Ternary::from_ratio(1, 4, 4), (ter("0"), ter("+-+-")));
+    // Integers
+    assert_eq!(Ternary::from_ratio(42, 1, 2), (Ternary::from_dec(42), ter("00")));
+    assert_eq!(Ternary::from_ratio(5, 7, 0), (ter("+"), ter("")));
+
+    // The approximation is within half a unit of the last digit.
+    for (num, den) in [(1, 7), (-22, 7), (355, 113), (-1, 5)] {
+        let (integer, fraction) = Ternary::from_ratio(num, den, 6);
+        let approx = integer.to_dec() * 729 + fraction.to_dec();
+        assert!((2 * (approx * den - num * 729)).abs() <= den.abs());
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "ternary-string")]
+#[test]
+fn test_is_canonical() {
+    use crate::*;
+
+    assert!(ter("+0-").is_canonical());
+    assert!(ter("-").is_canonical());
+    assert!(ter("0").is_canonical());
+    assert!(!ter("0+0-").is_canonical());
+    assert!(!ter("000").is_canonical());
+    assert!(!Ternary::new(vec![]).is_canonical());
+
+    for value in [-100, -1, 0, 1, 42] {
+        assert!(Ternary::from_dec(value).is_canonical());
+        let padded = Ternary::from_dec(value).with_length(8);
+        assert!(!padded.is_canonical());
+        assert!(padded.trim().is_canonical());
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "ternary-string")]
+#[test]
+fn test_trim_in_place() {
+    use crate::*;
+
+    for repr in ["00+-0", "+-0", "0", "000", "", "-", "0000-"] {
+        let mut ternary = ter(repr);
+        ternary.trim_in_place();
+        assert_eq!(ternary, ter(repr).trim());
+        assert!(ternary.is_canonical());
+    }
+
+    let mut ternary = Ternary::from_dec(-13).with_length(10);
+    ternary.trim_in_place();
+    assert_eq!(ternary.log(), 3);
+    assert_eq!(ternary.to_dec(), -13);
+}
+
+#[cfg(test)]
+#[cfg(feature = "ternary-string")]
+#[test]
+fn test_mod_neg() {
+    use crate::*;
+
+    let thirteen = ter("+++");
+    assert_eq!(thirteen.to_dec(), 13);
+    assert_eq!(ter("0").mod_neg(&thirteen), ter("0"));
+    assert_eq!(ter("000").mod_neg(&thirteen), ter("0"));
+    assert_eq!(ter("+").mod_neg(&thirteen), ter("++0"));
+    for value in -30..=30 {
+        let inverse = Ternary::from_dec(value).mod_neg(&thirteen).to_dec();
+        assert!((0..13).contains(&inverse));
+        assert_eq!((value + inverse).rem_euclid(13), 0);
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "ternary-string")]
+#[test]
+#[should_panic(expected = "Ternary::mod_neg(): Invalid modulus: -")]
+fn test_mod_neg_invalid_modulus() {
+    use crate::*;
+
+    ter("+").mod_neg(&ter("-"));
+}
+
+#[cfg(test)]
+#[cfg(feature = "ternary-string")]
+#[test]
+fn test_mod_add_mul() {
+    use crate::*;
+
+    let p = Ternary::from_dec(13);
+    for a in -20..=20 {
+        for b in [-14, -5, -1, 0, 1, 6, 12, 27] {
+            let (ta, tb) = (Ternary::from_dec(a), Ternary::from_dec(b));
+            assert_eq!(ta.mod_add(&tb, &p).to_dec(), (a + b).rem_euclid(13));
+            assert_eq!(ta.mod_mul(&tb, &p).to_dec(), (a * b).rem_euclid(13));
+        }
+    }
+    // Fermat's little theorem: 2^12 = 1 (mod 13).
+    let two = Ternary::from_dec(2);
+    let mut power = Ternary::from_dec(1);
+    for _ in 0..12 {
+        power = power.mod_mul(&two, &p);
+    }
+    assert_eq!(power, ter("+"));
+    // x + mod_neg(x) = 0
+    let x = Ternary::from_dec(-100);
+    assert_eq!(x.mod_add(&x.mod_neg(&p), &p), ter("0"));
+}
+
+#[cfg(test)]
+#[cfg(feature = "ternary-string")]
+#[test]
+fn test_each_zip_lengths() {
+    use crate::*;
+
+    // The previous implementation, padding the shortest operand with `with_length`.
+    fn each_zip_padded(a: &Ternary, f: impl Fn(Digit, Digit) -> Digit, b: &Ternary) -> Ternary {
+        if a.log() < b.log() {
+            return each_zip_padded(b, f, a);
+        }
+        let b = b.with_length(a.log());
+        let digits = a.iter().zip(b.iter()).map(|(x, y)| f(*x, *y)).collect();
+        Ternary::new(digits)
+    }
+
+    use core::ops::{BitAnd, Sub};
+
+    let values = ["", "0", "+", "-0+", "+-0+-", "00-+", "---000+++"];
+    let ops: [fn(Digit, Digit) -> Digit; 3] = [Digit::k3_imply, Digit::bi3_and, Digit::sub];
+    for a in values {
+        for b in values {
+            let (a, b) = (ter(a), ter(b));
+            for f in ops {
+                assert_eq!(a.each_zip(f, b.clone()), each_zip_padded(&a, f, &b));
+            }
+        }
+    }
+    // The longest operand is the first argument of `f`.
+    assert_eq!(ter("+").each_zip(Digit::k3_imply, ter("-0")), ter("++"));
+    assert_eq!(ter("-0").each_zip(Digit::k3_imply, ter("+")), ter("++"));
+
+    // Benchmark-like: large operands of different lengths.
+    let long = Ternary::new(vec![Pos; 100_000]);
+    let short = Ternary::new(vec![Neg; 1_000]);
+    let result = long.each_zip(Digit::bitand, short.clone());
+    assert_eq!(result.log(), 100_000);
+    assert_eq!(result.iter().filter(|d| **d == Neg).count(), 1_000);
+    assert_eq!(result, short.each_zip(Digit::bitand, long));
+}
+
+#[cfg(test)]
+#[cfg(feature = "ternary-string")]
+#[test]
+fn test_each_zip_carry_lengths() {
+    use crate::*;
+
+    // The previous implementation, recursing with a clone of the shortest operand.
+    fn each_zip_carry_padded(
+        a: &Ternary,
+        f: impl Fn(Digit, Digit, Digit) -> (Digit, Digit),
+        b: &Ternary,
+    ) -> Ternary {
+        if a.log() < b.log() {
+            return each_zip_carry_padded(b, f, a);
+        }
+        let b = b.with_length(a.log());
+        let mut digits = vec![];
+        let mut carry = Zero;
+        for (x, y) in a.iter().zip(b.iter()).rev() {
+            let (c, res) = f(*x, *y, carry);
+            carry = c;
+            digits.push(res);
+        }
+        digits.reverse();
+        Ternary::new(digits)
+    }
+
+    let full_adder = |a: Digit, b: Digit, carry: Digit| {
+        let sum = a.to_i8() + b.to_i8() + carry.to_i8();
+        let digit = (sum + 1).rem_euclid(3) - 1;
+        (Digit::from_i8((sum - digit) / 3), Digit::from_i8(digit))
+    };
+    // Not symmetric: the carry is the last implication.
+    let imply_chain = |a: Digit, b: Digit, carry: Digit| (a.k3_imply(b), b.bi3_and(carry).post());
+
+    let values = ["", "0", "+", "-0+", "+-0+-", "00-+", "---000+++"];
+    for a in values {
+        for b in values {
+            let (a, b) = (ter(a), ter(b));
+            assert_eq!(
+                a.each_zip_carry(full_adder, b.clone()),
+                each_zip_carry_padded(&a, full_adder, &b)
+            );
+            assert_eq!(
+                a.each_zip_carry(imply_chain, b.clone()),
+                each_zip_carry_padded(&a, imply_chain, &b)
+            );
+        }
+    }
+    // 2 + 8 = 10
+    assert_eq!(ter("+-").each_zip_carry(full_adder, ter("+0-")), ter("+0+"));
+    assert_eq!(ter("+0-").each_zip_carry(full_adder, ter("+-")), ter("+0+"));
+}
+
+#[cfg(test)]
+#[cfg(feature = "ternary-string")]
+#[test]
+fn test_sum_all() {
+    use crate::*;
+
+    assert_eq!(Ternary::sum_all(&[ter("+"), ter("+"), ter("+")]), ter("+0"));
+    assert_eq!(Ternary::sum_all(&[]), ter("0"));
+    assert_eq!(Ternary::sum_all(&[ter("")]), ter("0"));
+    assert_eq!(Ternary::sum_all(&[ter("00+-")]), ter("+-"));
+    assert_eq!(Ternary::sum_all(&[ter("+"), ter("-")]), ter("0"));
+
+    let values = [-1000, 364, 7, 0, -13, 99_999, 42];
+    let terms: Vec<Ternary> = values.iter().map(|v| Ternary::from_dec(*v)).collect();
+    assert_eq!(Ternary::sum_all(&terms).to_dec(), values.iter().sum::<i64>());
+
+    // Many carries in the same column.
+    let terms = vec![ter("+++"); 100];
+    assert_eq!(Ternary::sum_all(&terms).to_dec(), 1300);
+    let terms = vec![ter("---"); 100];
+    assert_eq!(Ternary::sum_all(&terms).to_dec(), -1300);
+
+    // Beyond i64.
+    let big = Ternary::new(vec![Pos; 50]);
+    assert_eq!(Ternary::sum_all(&[big.clone(), -&big]), ter("0"));
+    let expected = ter("+").concat(&Ternary::new(vec![Neg; 50]));
+    assert_eq!(Ternary::sum_all(&[big.clone(), ter("+")]), expected);
+}
+
+#[cfg(test)]
+#[cfg(feature = "ternary-string")]
+#[test]
+fn test_bounds_for_len() {
+    use crate::*;
+
+    assert_eq!(Ternary::max_for_len(3).to_dec(), 13);
+    assert_eq!(Ternary::min_for_len(3).to_dec(), -13);
+    assert_eq!(Ternary::zero_for_len(3), ter("000"));
+    assert_eq!(Ternary::max_for_len(0).log(), 0);
+    for len in 1..=20 {
+        let max = Ternary::max_for_len(len);
+        assert_eq!(max.log(), len);
+        assert_eq!(Ternary::min_for_len(len), -&max);
+        assert_eq!(Ternary::zero_for_len(len).log(), len);
+        // The next value needs one more digit.
+        assert_eq!(Ternary::from_dec(max.to_dec() + 1).log(), len + 1);
+    }
+    assert_eq!(Ternary::max_for_len(40).to_dec(), 6_078_832_729_528_464_400);
+}
+
+#[cfg(test)]
+#[cfg(feature = "ternary-string")]
+#[test]
+fn test_histogram() {
+    use crate::*;
+
+    assert_eq!(ter("+0-0+").histogram(), [1, 2, 2]);
+    assert_eq!(ter("").histogram(), [0, 0, 0]);
+    assert_eq!(ter("0").histogram(), [0, 1, 0]);
+    assert_eq!(Ternary::min_for_len(7).histogram(), [7, 0, 0]);
+
+    let ternary = Ternary::from_dec(-123_456_789);
+    let [neg, zero, pos] = ternary.histogram();
+    assert_eq!(neg + zero + pos, ternary.log());
+}
+
+#[cfg(test)]
+#[cfg(feature = "ternary-string")]
+#[test]
+fn test_sign_magnitude() {
+    use crate::*;
+
+    assert_eq!(ter("-+").to_sign_magnitude(), (Neg, ter("+-")));
+    assert_eq!(ter("+-").to_sign_magnitude(), (Pos, ter("+-")));
+    assert_eq!(ter("0").to_sign_magnitude(), (Zero, ter("0")));
+    assert_eq!(ter("").to_sign_magnitude(), (Zero, ter("0")));
+    for value in [-1000, -13, -1, 1, 42, 99_999] {
+        let (sign, magnitude) = Ternary::from_dec(value).to_sign_magnitude();
+        assert_eq!(sign.to_i8() as i64, value.signum());
+        assert_eq!(magnitude, Ternary::from_dec(value.abs()));
+    }
+    // Beyond i64.
+    let (sign, magnitude) = Ternary::min_for_len(50).to_sign_magnitude();
+    assert_eq!((sign, magnitude), (Neg, Ternary::max_for_len(50)));
+}
+
+#[cfg(test)]
+#[cfg(feature = "ternary-string")]
+#[test]
+fn test_gray_code() {
+    use crate::*;
+
+    assert_eq!(ter("0").to_gray(), ter("0"));
+    assert_eq!(ter("-").to_gray(), ter("-"));
+    assert_eq!(ter("+--").to_gray(), ter("++-"));
+    assert_eq!(ter("00+-").to_gray(), ter("00++"));
+    for value in -500..500 {
+        let current = Ternary::from_dec(value).with_length(8).to_gray();
+        let next = Ternary::from_dec(value + 1).with_length(8).to_gray();
+        let changes = current
+            .iter()
+            .zip(next.iter())
+            .filter(|(a, b)| a != b)
+            .count();
+        assert_eq!(changes, 1, "{} -> {}", value, value + 1);
+        assert_eq!(current.from_gray().to_dec(), value);
+        assert_eq!(Ternary::from_dec(value).to_gray().to_dec(), current.to_dec());
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "ternary-string")]
+#[test]
+fn test_eval_at() {
+    use crate::*;
+
+    assert_eq!(ter("+0-").eval_at(10), Some(99));
+    assert_eq!(ter("-0+").eval_at(10), Some(-99));
+    assert_eq!(ter("+-+").eval_at(-2), Some(7));
+    assert_eq!(ter("++").eval_at(0), Some(1));
+    assert_eq!(ter("").eval_at(7), Some(0));
+    assert_eq!(ter("00+").eval_at(i64::MAX), Some(1));
+    assert_eq!(ter("+0").eval_at(i64::MAX), Some(i64::MAX));
+    assert_eq!(ter("++").eval_at(i64::MAX), None);
+    for value in [-9_999, -1, 0, 1, 12_345] {
+        assert_eq!(Ternary::from_dec(value).eval_at(3), Some(value));
+    }
+    assert_eq!(Ternary::max_for_len(41).eval_at(3), None);
+    assert_eq!(Ternary::from_dec(i64::MIN).eval_at(3), Some(i64::MIN));
+    assert_eq!(Ternary::from_dec(i64::MAX).eval_at(3), Some(i64::MAX));
+}
+
+#[cfg(test)]
+#[cfg(feature = "ternary-string")]
+#[test]
+fn test_from_char_iter() {
+    use crate::*;
+
+    assert_eq!(Ternary::from_char_iter("+-0+".chars()), Ok(ter("+-0+")));
+    assert_eq!(Ternary::from_char_iter(core::iter::empty()), Ok(ter("")));
+    let stream = ['+', '+'].into_iter().cycle().take(6);
+    assert_eq!(Ternary::from_char_iter(stream), Ok(ter("++++++")));
+
+    // The iterator is not consumed past the first invalid character.
+    let mut chars = "+0a--".chars();
+    assert_eq!(Ternary::from_char_iter(&mut chars), Err(ParseTernaryError));
+    assert_eq!(chars.as_str(), "--");
+}
+
+#[cfg(test)]
+#[cfg(feature = "ternary-string")]
+#[test]
+fn test_sign_extend() {
+    use crate::*;
+
+    let ternary = ter("+-0");
+    assert_eq!(ternary.sign_extend(5).to_string(), "+++-0");
+    assert_eq!(ternary.with_length(5).to_string(), "00+-0");
+    assert_ne!(ternary.sign_extend(5).to_dec(), ternary.to_dec());
+    assert_eq!(ternary.with_length(5).to_dec(), ternary.to_dec());
+
+    // A leading zero makes both methods equivalent.
+    let ternary = ter("0-+");
+    assert_eq!(ternary.sign_extend(6), ternary.with_length(6));
+    assert_eq!(ternary.sign_extend(6).to_dec(), ternary.to_dec());
+
+    assert_eq!(ter("-+").sign_extend(1).to_string(), "-+");
+    assert_eq!(ter("-+").sign_extend(2).to_string(), "-+");
+    assert_eq!(ter("").sign_extend(3).to_string(), "000");
+}
+
+#[cfg(test)]
+#[cfg(feature = "ternary-string")]
+#[test]
+fn test_checked_mul_pow3() {
+    use crate::*;
+
+    let ternary = ter("+-"); // 2
+    assert_eq!(ternary.checked_mul_pow3(0, 2), Some(ter("+-")));
+    assert_eq!(ternary.checked_mul_pow3(0, 1), None);
+    assert_eq!(ternary.checked_mul_pow3(4, 6), Some(ter("+-0000")));
+    assert_eq!(ternary.checked_mul_pow3(4, 6).unwrap().to_dec(), 162);
+    assert_eq!(ternary.checked_mul_pow3(5, 6), None);
+    // Leading zeros do not count.
+    assert_eq!(ter("000-").checked_mul_pow3(2, 3), Some(ter("-00")));
+    // Zero stays a single digit.
+    assert_eq!(ter("00").checked_mul_pow3(10, 1), Some(ter("0")));
+    assert_eq!(Ternary::max_for_len(50).checked_mul_pow3(10, 60).unwrap().log(), 60);
+}
+
+#[cfg(test)]
+#[cfg(feature = "ternary-string")]
+#[test]
+fn test_all_of_length() {
+    use crate::*;
+    use alloc::collections::BTreeSet;
+
+    let all = Ternary::all_of_length(2).collect::<Vec<_>>();
+    assert_eq!(all.len(), 9);
+    assert_eq!(all.iter().collect::<BTreeSet<_>>().len(), 9);
+    assert_eq!(all[0], ter("--"));
+    assert_eq!(all[4], ter("00"));
+    assert_eq!(all[8], ter("++"));
+    assert!(all.iter().all(|t| t.log() == 2));
+
+    assert_eq!(Ternary::all_of_length(5).count(), 243);
+    assert!(Ternary::all_of_length(4)
+        .zip(-40..=40)
+        .all(|(t, value)| t.to_dec() == value));
+    assert_eq!(Ternary::all_of_length(0).collect::<Vec<_>>(), vec![ter("")]);
+}
+
+#[cfg(test)]
+#[cfg(feature = "ternary-string")]
+#[test]
+fn test_digits_needed() {
+    use crate::*;
+
+    assert_eq!(Ternary::digits_needed(0), 0);
+    assert_eq!(Ternary::digits_needed(1), 1);
+    assert_eq!(Ternary::digits_needed(-1), 1);
+    assert_eq!(Ternary::digits_needed(2), 2);
+    assert_eq!(Ternary::digits_needed(13), 3);
+    assert_eq!(Ternary::digits_needed(14), 4);
+    assert_eq!(Ternary::digits_needed(-13), 3);
+    for value in (-5_000..5_000).chain([i64::MIN, i64::MIN + 1, i64::MAX]) {
+        if value != 0 {
+            assert_eq!(Ternary::digits_needed(value), Ternary::from_dec(value).log());
+        }
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "ternary-string")]
+#[test]
+fn test_each_tracked() {
+    use crate::*;
+
+    let ternary = ter("+0-0");
+    assert_eq!(ternary.each_tracked(|d| d), (ter("+0-0"), false));
+    assert_eq!(ternary.each_tracked(Digit::post), (ter("-+0+"), true));
+    assert_eq!(ternary.each_tracked(Digit::post).0, ternary.each(Digit::post));
+    // Only the `Zero` digits change.
+    assert_eq!(ternary.each_tracked(Digit::possibly), (ter("++-+"), true));
+    assert_eq!(ter("+00+").each_tracked(Digit::absolute_positive), (ter("+00+"), false));
+    assert_eq!(ter("").each_tracked(Digit::post), (ter(""), false));
+}
+
+#[cfg(test)]
+#[cfg(feature = "ternary-string")]
+#[test]
+fn test_concat_all() {
+    use crate::*;
+
+    let a = ter("+0-");
+    let b = ter("--");
+    let c = ter("0++0");
+    let all = Ternary::concat_all(&[a.clone(), b.clone(), c.clone()]);
+    assert_eq!(all, a.concat(&b).concat(&c));
+    assert_eq!(all.to_string(), "+0---0++0");
+    assert_eq!(all.log(), 9);
+    assert_eq!(Ternary::concat_all(&[ter("--")]).to_string(), "--");
+    assert_eq!(Ternary::concat_all(&[]), ter(""));
+}
+
+#[cfg(test)]
+#[cfg(feature = "tryte")]
+#[test]
+fn test_to_trytes() {
+    use crate::*;
+
+    let ternary = ter("+-0+-0+0-+-0+");
+    assert_eq!(ternary.log(), 13);
+    let trytes = ternary.to_trytes::<6>();
+    assert_eq!(trytes.len(), 3);
+    assert_eq!(trytes[0], Tryte::<6>::from("0-+-0+"));
+    assert_eq!(trytes[1], Tryte::<6>::from("-0+-0+"));
+    assert_eq!(trytes[2], Tryte::<6>::from("00000+"));
+    let rebuilt: Vec<Ternary> = trytes.iter().rev().map(Tryte::to_ternary).collect();
+    assert_eq!(Ternary::concat_all(&rebuilt).trim(), ternary);
+    assert_eq!(ter("").to_trytes::<6>(), vec![]);
+    assert_eq!(ter("+-0+-0").to_trytes::<6>(), vec![Tryte::<6>::from("+-0+-0")]);
+}
+
+#[cfg(test)]
+#[cfg(feature = "tryte")]
+#[test]
+fn test_from_trytes() {
+    use crate::*;
+
+    let ternary = ter("+-0+-0+0-+-0+");
+    let trytes = ternary.to_trytes::<6>();
+    assert_eq!(Ternary::from_trytes(&trytes), ternary);
+    assert_eq!(Ternary::from_trytes(&trytes).to_dec(), ternary.to_dec());
+    for value in [-1_000_000, -42, 0, 1, 364, 365, 9_999_999] {
+        let ternary = Ternary::from_dec(value);
+        assert_eq!(Ternary::from_trytes(&ternary.to_trytes::<4>()), ternary);
+    }
+    assert_eq!(Ternary::from_trytes(&[Tryte::<6>::ZERO, Tryte::ZERO]), ter("0"));
+    assert_eq!(Ternary::from_trytes::<6>(&[]), ter("0"));
+}
+
+#[cfg(test)]
+#[cfg(feature = "ternary-string")]
+#[test]
+fn test_approx_eq() {
+    use crate::*;
+
+    let one = ter("+");
+    assert!(ter("+0-").approx_eq(&ter("+0-"), &one));
+    assert!(ter("+0-").approx_eq(&ter("+00"), &one));
+    assert!(ter("+00").approx_eq(&ter("+0-"), &one));
+    assert!(!ter("+0-").approx_eq(&ter("+0+"), &one));
+    assert!(ter("-").approx_eq(&ter("0"), &one));
+    assert!(!ter("-").approx_eq(&ter("+"), &one));
+    assert!(ter("-").approx_eq(&ter("+"), &ter("+-")));
+    assert!(ter("+0-").approx_eq(&ter("000+0-"), &ter("0")));
+    assert!(!ter("+0-").approx_eq(&ter("+0-"), &ter("-")));
+    // Far beyond the range of an `i64`.
+    let big = Ternary::parse(&"+".repeat(60));
+    assert!(big.approx_eq(&big, &ter("0")));
+    assert!(big.approx_eq(&Ternary::parse(&("+".repeat(59) + "0")), &one));
+    assert!(!big.approx_eq(&Ternary::parse(&("+".repeat(59) + "-")), &one));
+}
+
+#[cfg(test)]
+#[cfg(feature = "ternary-string")]
+#[test]
+fn test_to_hdl_literal() {
+    use crate::*;
+
+    assert_eq!(ter("+0-").to_hdl_literal("b"), "3'b+0-");
+    assert_eq!(Ternary::from_dec(-5).to_hdl_literal("t"), "3't-++");
+    assert_eq!(Ternary::from_dec(5).with_length(6).to_hdl_literal("t"), "6't000+--");
+    assert_eq!(ter("0").to_hdl_literal(""), "1'0");
+    assert_eq!(ter("").to_hdl_literal("t"), "1't0");
+}
+
+#[cfg(test)]
+#[cfg(feature = "ternary-string")]
+#[test]
+fn test_is_divisible_by() {
+    use crate::*;
+
+    let three = ter("+0");
+    // Divisible by 3 iff the least significant digit is `Zero`.
+    for value in -50..=50 {
+        let ternary = Ternary::from_dec(value);
+        assert_eq!(ternary.is_divisible_by(&three), value % 3 == 0);
+        assert_eq!(ternary.is_divisible_by(&three), ternary.digit(0).unwrap_or(Zero) == Zero);
+        assert_eq!(ternary.is_divisible_by(&ter("-+")), value % 2 == 0);
+        assert_eq!(ternary.is_divisible_by(&ter("-00")), value % 9 == 0);
+    }
+    assert!(ter("+-0").is_divisible_by(&ter("000+0")));
+    assert!(ter("00").is_divisible_by(&ter("+00")));
+    assert!(!ter("+0").is_divisible_by(&ter("+00")));
+    assert!(ter("+").is_divisible_by(&ter("-")));
+    let big = Ternary::parse(&("+-".repeat(30) + "000"));
+    assert!(big.is_divisible_by(&ter("+000")));
+    assert!(!big.is_divisible_by(&ter("+0000")));
+    assert!(big.is_divisible_by(&ter("++")));
+}
+
+#[cfg(test)]
+#[cfg(feature = "ternary-string")]
+#[test]
+#[should_panic(expected = "Cannot divide by zero.")]
+fn test_is_divisible_by_zero() {
+    crate::ter("+").is_divisible_by(&crate::ter("00"));
+}
+
+#[cfg(test)]
+#[cfg(feature = "ternary-string")]
+#[test]
+fn test_to_aligned_string() {
+    use crate::*;
+
+    assert_eq!(ter("+0-").to_aligned_string(6), "   +0-");
+    assert_eq!(ter("+0-").to_aligned_string(3), "+0-");
+    assert_eq!(ter("00+").to_aligned_string(4), " 00+");
+    assert_eq!(ter("").to_aligned_string(2), " 0");
+    let column: Vec<String> = [1, -40, 364]
+        .iter()
+        .map(|value| Ternary::from_dec(*value).to_aligned_string(6))
+        .collect();
+    assert_eq!(column, vec!["     +", "  ----", "++++++"]);
+}
+
+#[cfg(test)]
+#[cfg(feature = "ternary-string")]
+#[test]
+#[should_panic]
+fn test_to_aligned_string_too_wide() {
+    crate::ter("+0-").to_aligned_string(2);
+}
+
+#[cfg(test)]
+#[cfg(feature = "ternary-string")]
+#[test]
+fn test_write_to() {
+    use crate::*;
+    use core::fmt::Write;
+
+    /// A fixed-capacity sink, as used on embedded targets.
+    struct FixedBuffer {
+        buffer: [u8; 8],
+        len: usize,
+    }
+
+    impl Write for FixedBuffer {
+        fn write_str(&mut self, s: &str) -> core::fmt::Result {
+            let end = self.len + s.len();
+            if end > self.buffer.len() {
+                return Err(core::fmt::Error);
+            }
+            self.buffer[self.len..end].copy_from_slice(s.as_bytes());
+            self.len = end;
+            Ok(())
         }
-        repr.digits.reverse();
-        repr
     }
+
+    let ternary = ter("+0--+0");
+    let mut sink = FixedBuffer { buffer: [0; 8], len: 0 };
+    assert_eq!(ternary.write_to(&mut sink), Ok(()));
+    assert_eq!(&sink.buffer[..sink.len], ternary.to_string().as_bytes());
+
+    let mut sink = FixedBuffer { buffer: [0; 8], len: 0 };
+    assert_eq!(ter("+0-+0-+0-").write_to(&mut sink), Err(core::fmt::Error));
+
+    let mut string = String::new();
+    Ternary::from_dec(-1_234).write_to(&mut string).unwrap();
+    assert_eq!(string, Ternary::from_dec(-1_234).to_string());
 }
 
+#[cfg(test)]
 #[cfg(feature = "ternary-string")]
-impl Display for Ternary {
-    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
-        write!(f, "{}", self.to_string_repr(Digit::to_char))
+#[test]
+fn test_stable_hash() {
+    use crate::*;
+
+    assert_eq!(ter("00+").stable_hash(), ter("+").stable_hash());
+    assert_eq!(ter("000").stable_hash(), ter("0").stable_hash());
+    assert_eq!(ter("").stable_hash(), ter("0").stable_hash());
+    assert_ne!(ter("+").stable_hash(), ter("-").stable_hash());
+    assert_ne!(ter("+").stable_hash(), ter("0").stable_hash());
+    assert_ne!(ter("+0").stable_hash(), ter("+").stable_hash());
+    // The value is fixed, whatever the platform.
+    assert_eq!(ter("0").stable_hash(), 0xcbf2_9ce4_8422_2325);
+    for value in -100..100 {
+        let ternary = Ternary::from_dec(value);
+        assert_eq!(ternary.stable_hash(), ternary.with_length(10).stable_hash());
+        assert_ne!(ternary.stable_hash(), Ternary::from_dec(value + 1).stable_hash());
     }
 }
 
+#[cfg(test)]
 #[cfg(feature = "ternary-string")]
-impl FromStr for Ternary {
-    type Err = ParseTernaryError;
+#[test]
+fn test_empty_ternary() {
+    use crate::*;
 
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        if s.chars().all(|c| matches!(c, '+' | '0' | '-')) {
-            Ok(Ternary::parse(s))
-        } else {
-            Err(ParseTernaryError)
-        }
-    }
+    let empty = Ternary::new(vec![]);
+    assert_eq!(empty.to_string(), "0");
+    assert_eq!(empty.to_string_repr(Digit::to_char_t), "0");
+    assert_eq!(format!("{}", empty), "0");
+    assert_eq!(empty.to_dec(), 0);
+    assert_eq!(empty, 0);
+    assert_eq!(empty.trim(), ter("0"));
+
+    let one = ter("+");
+    assert_eq!(&empty + &one, one);
+    assert_eq!(&one + &empty, one);
+    assert_eq!(&one - &empty, one);
+    assert_eq!(&empty - &one, ter("-"));
+    assert_eq!(&empty * &one, ter("0"));
+    assert_eq!(&empty / &one, ter("0"));
+    assert_eq!(&empty % &one, ter("0"));
+    assert_eq!((-&empty).to_dec(), 0);
+    assert_eq!((&empty & &one).to_dec(), 0);
+    assert_eq!((&empty | &one).to_dec(), 1);
+    assert_eq!(empty.long_div(&one), (ter("0"), ter("0")));
+    assert!(empty.is_divisible_by(&one));
+    assert_eq!(empty.cmp(&ter("0")), core::cmp::Ordering::Equal);
+    assert_eq!(empty.cmp(&one), core::cmp::Ordering::Less);
 }
 
+#[cfg(test)]
 #[cfg(feature = "ternary-string")]
-impl Ord for Ternary {
-    fn cmp(&self, other: &Self) -> Ordering {
-        self.to_dec().cmp(&other.to_dec())
-    }
+#[test]
+fn test_parse_mixed() {
+    use crate::*;
+
+    assert_eq!(Ternary::parse_mixed("t:+0-"), Ok(ter("+0-")));
+    assert_eq!(Ternary::parse_mixed("t:00+"), Ok(ter("00+")));
+    assert_eq!(Ternary::parse_mixed("n:12"), Ok(Ternary::from_dec(11)));
+    assert_eq!(Ternary::parse_mixed("n:-12"), Ok(Ternary::from_dec(-11)));
+    assert_eq!(Ternary::parse_mixed("c:A3"), Ok(Ternary::from_dec(273)));
+    assert_eq!(Ternary::parse_mixed("c:q"), Ok(Ternary::from_dec(26)));
+    assert_eq!(
+        Ternary::parse_mixed("t:+0-|n:12|c:A3"),
+        Ok(ter("+0-").concat(&ter("++-")).concat(&ter("+0+0+0")))
+    );
+    assert_eq!(Ternary::parse_mixed("n:8|t:0|n:8").unwrap().to_string(), "+0-0+0-");
+
+    assert_eq!(Ternary::parse_mixed(""), Err(ParseTernaryError));
+    assert_eq!(Ternary::parse_mixed("t:"), Err(ParseTernaryError));
+    assert_eq!(Ternary::parse_mixed("n:"), Err(ParseTernaryError));
+    assert_eq!(Ternary::parse_mixed("+0-"), Err(ParseTernaryError));
+    assert_eq!(Ternary::parse_mixed("t:+0-|"), Err(ParseTernaryError));
+    assert_eq!(Ternary::parse_mixed("t:12"), Err(ParseTernaryError));
+    assert_eq!(Ternary::parse_mixed("n:9"), Err(ParseTernaryError));
+    assert_eq!(Ternary::parse_mixed("c:R"), Err(ParseTernaryError));
+    assert_eq!(Ternary::parse_mixed(&("c:".to_string() + &"Q".repeat(20))), Err(ParseTernaryError));
 }
 
+#[cfg(test)]
 #[cfg(feature = "ternary-string")]
-impl PartialOrd for Ternary {
-    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-        Some(self.cmp(other))
+#[test]
+fn test_digit_root() {
+    use crate::*;
+
+    assert_eq!(ter("+++").digit_sum(), 3);
+    assert_eq!(ter("---").digit_sum(), -3);
+    assert_eq!(ter("+0-").digit_sum(), 0);
+    assert_eq!(ter("").digit_sum(), 0);
+    assert_eq!(Ternary::parse(&"+".repeat(100)).digit_sum(), 100);
+
+    assert_eq!(ter("+++").digit_root(), Pos);
+    assert_eq!(ter("---").digit_root(), Neg);
+    assert_eq!(ter("+0-").digit_root(), Zero);
+    assert_eq!(ter("").digit_root(), Zero);
+    // 100 = "++-0+" -> 2 = "+-" -> 0.
+    assert_eq!(Ternary::parse(&"+".repeat(100)).digit_root(), Zero);
+    assert_eq!(Ternary::parse(&"+".repeat(101)).digit_root(), Pos);
+    for value in -200..200 {
+        let ternary = Ternary::from_dec(value);
+        // Every power of 3 is odd, so the parity of the value is kept.
+        assert_eq!(ternary.digit_root() == Zero, value % 2 == 0);
     }
 }
 
+#[cfg(test)]
 #[cfg(feature = "ternary-string")]
-impl IntoIterator for Ternary {
-    type Item = Digit;
-    type IntoIter = alloc::vec::IntoIter<Digit>;
+#[test]
+fn test_digit_max_min() {
+    use crate::*;
 
-    fn into_iter(self) -> Self::IntoIter {
-        self.digits.into_iter()
+    assert_eq!(ter("+0-").digit_max(&ter("-0+")), ter("+0+"));
+    assert_eq!(ter("+0-").digit_min(&ter("-0+")), ter("-0-"));
+    assert_eq!(ter("-").digit_max(&ter("-+-")), ter("0+-"));
+    assert_eq!(ter("-+-").digit_min(&ter("+")), ter("-0-"));
+    // Per position, not numeric: "+--" (5) and "0++" (4) give "+++" (13) and "0--" (-4).
+    assert_eq!(ter("+--").digit_max(&ter("0++")), ter("+++"));
+    assert_eq!(ter("+--").digit_min(&ter("0++")), ter("0--"));
+    for (a, b) in [(ter("+0-+"), ter("-0+0")), (ter("--"), ter("0+0+"))] {
+        assert_eq!(a.digit_max(&b), &a | &b);
+        assert_eq!(a.digit_min(&b), &a & &b);
+        assert_eq!(a.digit_max(&b), b.digit_max(&a));
     }
 }
 
+#[cfg(test)]
 #[cfg(feature = "ternary-string")]
-mod operations;
+#[test]
+fn test_is_palindrome() {
+    use crate::*;
 
-mod conversions;
+    assert!(ter("+0+").is_palindrome());
+    assert!(!ter("+0-").is_palindrome());
+    assert!(ter("-++-").is_palindrome());
+    assert!(ter("0").is_palindrome());
+    assert!(ter("").is_palindrome());
+    assert!(!ter("00+").is_palindrome());
+    assert!(ter("0+0").is_palindrome());
 
-#[cfg(feature = "ternary-store")]
-mod store;
+    assert!(ter("+0+").is_palindrome_trimmed());
+    assert!(!ter("+0-").is_palindrome_trimmed());
+    assert!(ter("00+").is_palindrome_trimmed());
+    assert!(ter("00-0-").is_palindrome_trimmed());
+    assert!(!ter("0+0").is_palindrome_trimmed());
+    assert!(ter("000").is_palindrome_trimmed());
+}
 
-#[cfg(feature = "ternary-store")]
-pub use crate::store::{Ter40, DataTernary, TritsChunk};
+#[cfg(test)]
+#[cfg(feature = "ternary-string")]
+#[test]
+fn test_rle() {
+    use crate::*;
 
-#[cfg(feature = "tryte")]
-mod tryte;
+    let ternary = ter("000++0--");
+    let runs = ternary.to_rle();
+    assert_eq!(runs, vec![(Zero, 3), (Pos, 2), (Zero, 1), (Neg, 2)]);
+    assert_eq!(Ternary::from_rle(&runs), ternary);
+    assert_eq!(ter("").to_rle(), vec![]);
+    assert_eq!(Ternary::from_rle(&[]), ter(""));
+    assert_eq!(Ternary::from_rle(&[(Pos, 2), (Neg, 0), (Pos, 1)]), ter("+++"));
 
-#[cfg(feature = "tryte")]
-pub use crate::tryte::Tryte;
+    let sparse = Ternary::parse(&("+".to_string() + &"0".repeat(100) + "-"));
+    assert_eq!(sparse.to_rle(), vec![(Pos, 1), (Zero, 100), (Neg, 1)]);
+    assert_eq!(Ternary::from_rle(&sparse.to_rle()), sparse);
+    for value in -100..100 {
+        let ternary = Ternary::from_dec(value);
+        assert_eq!(Ternary::from_rle(&ternary.to_rle()), ternary);
+    }
+}
 
 #[cfg(test)]
 #[cfg(feature = "ternary-string")]
 #[test]
-fn test_ternary() {
+fn test_complement() {
     use crate::*;
 
-    let repr5 = Ternary::new(vec![Pos, Neg, Neg]);
-    assert_eq!(repr5.to_dec(), 5);
-    let repr5 = Ternary::from_dec(5);
-    assert_eq!(repr5.to_dec(), 5);
-
-    let repr13 = Ternary::new(vec![Pos, Pos, Pos]);
-    assert_eq!(repr13.to_dec(), 13);
+    assert_eq!(ter("+0-").complement(3), ter("-0+"));
+    assert_eq!(ter("+").complement(3), ter("00-"));
+    assert_eq!(ter("0").complement(2), ter("00"));
+    // Wider than the width: only the least significant digits are complemented.
+    assert_eq!(ter("+-0+").complement(2), ter("0-"));
 
-    let repr14 = Ternary::parse("+---");
-    let repr15 = Ternary::parse("+--0");
-    assert_eq!(repr14.to_dec(), 14);
-    assert_eq!(repr15.to_dec(), 15);
-    assert_eq!(repr14.to_string(), "+---");
-    assert_eq!(repr15.to_string(), "+--0");
+    // Complement-based subtraction matches direct subtraction within the width.
+    let width = 6;
+    let modulus = 3_i64.pow(width as u32);
+    let max = (modulus - 1) / 2;
+    for a in [-364, -100, -1, 0, 1, 42, 364] {
+        for b in [-364, -7, 0, 5, 300] {
+            let complement = Ternary::from_dec(b).complement(width);
+            assert_eq!(complement.log(), width);
+            assert_eq!((modulus - b - complement.to_dec()).rem_euclid(modulus), 0);
+            let difference = (&Ternary::from_dec(a) + &complement).to_fixed_width_wrapping(width);
+            let expected = Ternary::from_dec(a - b).to_fixed_width_wrapping(width);
+            assert_eq!(difference, expected);
+            if (-max..=max).contains(&(a - b)) {
+                assert_eq!(difference.to_dec(), a - b);
+            }
+        }
+    }
+}
 
-    let repr120 = Ternary::from_dec(120);
-    assert_eq!(repr120.to_dec(), 120);
-    assert_eq!(repr120.to_string(), "++++0");
-    let repr121 = Ternary::from_dec(121);
-    assert_eq!(repr121.to_dec(), 121);
-    assert_eq!(repr121.to_string(), "+++++");
+#[cfg(test)]
+#[cfg(feature = "ternary-string")]
+#[test]
+fn test_glyphs() {
+    use crate::*;
 
-    let repr_neg_5 = Ternary::parse("-++");
-    assert_eq!(repr_neg_5.to_dec(), -5);
-    assert_eq!(repr_neg_5.to_string(), "-++");
+    let glyphs = DigitGlyphs { neg: '↓', zero: '·', pos: '↑' };
+    for value in -50..50 {
+        let ternary = Ternary::from_dec(value);
+        let string = ternary.to_string_with_glyphs(&glyphs);
+        assert_eq!(Ternary::parse_with_glyphs(&string, &glyphs), Ok(ternary.clone()));
+        assert_eq!(ternary.to_string_with_glyphs(&DigitGlyphs::STANDARD), ternary.to_string());
+        assert_eq!(
+            ternary.to_string_with_glyphs(&DigitGlyphs::T),
+            ternary.to_string_repr(Digit::to_char_t)
+        );
+    }
+    assert_eq!(ter("+0-").to_string_with_glyphs(&glyphs), "↑·↓");
+    assert_eq!(Ternary::parse_with_glyphs("1T0", &DigitGlyphs::T), Ok(ter("+-0")));
+    assert_eq!(Ternary::parse_with_glyphs("1-0", &DigitGlyphs::T), Err(ParseTernaryError));
+    assert_eq!(Ternary::parse_with_glyphs("", &DigitGlyphs::T), Ok(ter("")));
+}
 
-    let repr_neg_5 = Ternary::from_dec(-5);
-    assert_eq!(repr_neg_5.to_dec(), -5);
-    assert_eq!(repr_neg_5.to_string(), "-++");
+#[cfg(test)]
+#[cfg(feature = "ternary-string")]
+#[test]
+fn test_next_digit_permutation() {
+    use crate::*;
 
-    let repr_neg_121 = Ternary::from_dec(-121);
-    assert_eq!(repr_neg_121.to_dec(), -121);
-    assert_eq!(repr_neg_121.to_string(), "-----");
+    let mut permutations = vec![ter("-0+")];
+    while let Some(next) = permutations.last().unwrap().next_digit_permutation() {
+        permutations.push(next);
+    }
+    assert_eq!(
+        permutations,
+        vec![ter("-0+"), ter("-+0"), ter("0-+"), ter("0+-"), ter("+-0"), ter("+0-")]
+    );
 
-    let test = Ternary::from_dec(18887455);
-    assert_eq!(test.to_dec(), 18887455);
-    assert_eq!(test.to_string(), "++00--0--+-0++0+");
+    // Repeated digits give distinct arrangements only: 4! / (2! * 2!) = 6.
+    let mut count = 1;
+    let mut current = ter("--++");
+    while let Some(next) = current.next_digit_permutation() {
+        assert_eq!(next.histogram(), current.histogram());
+        current = next;
+        count += 1;
+    }
+    assert_eq!(count, 6);
+    assert_eq!(current, ter("++--"));
 
-    let unbalanced = Ternary::from_unbalanced("12");
-    assert_eq!(unbalanced.to_dec(), 5);
-    assert_eq!(unbalanced.to_string(), "+--");
+    assert_eq!(ter("").next_digit_permutation(), None);
+    assert_eq!(ter("0").next_digit_permutation(), None);
+    assert_eq!(ter("000").next_digit_permutation(), None);
+    assert_eq!(ter("0+-").next_digit_permutation(), Some(ter("+-0")));
+}
 
-    let unbalanced = Ternary::from_unbalanced("-12");
-    assert_eq!(unbalanced.to_dec(), -5);
-    assert_eq!(unbalanced.to_string(), "-++");
+#[cfg(test)]
+#[cfg(feature = "ternary-string")]
+#[test]
+fn test_carry_chain_length() {
+    use crate::*;
 
-    let unbalanced = Ternary::from_dec(121);
-    assert_eq!(unbalanced.to_unbalanced(), "11111");
-    assert_eq!(unbalanced.to_string(), "+++++");
+    // Worst case: every position carries.
+    for length in 1..10 {
+        let all_pos = Ternary::parse(&"+".repeat(length));
+        assert_eq!(all_pos.carry_chain_length(&all_pos), length);
+        let all_neg = -&all_pos;
+        assert_eq!(all_neg.carry_chain_length(&all_neg), length);
+    }
+    // Carry-free cases.
+    assert_eq!(ter("+0-").carry_chain_length(&ter("0+0")), 0);
+    assert_eq!(ter("+++").carry_chain_length(&ter("---")), 0);
+    assert_eq!(ter("+-+-").carry_chain_length(&ter("")), 0);
+    // 1 + 1 = "+-": a single carry, which does not propagate.
+    assert_eq!(ter("+").carry_chain_length(&ter("+")), 1);
+    // "0++" + "+": the carry of the first position propagates through the second one.
+    assert_eq!(ter("0++").carry_chain_length(&ter("+")), 2);
+    assert_eq!(ter("+0+").carry_chain_length(&ter("00+")), 1);
+    assert_eq!(ter("0++").carry_chain_length(&ter("+++")), 3);
+    assert_eq!(ter("++0++").carry_chain_length(&ter("++0++")), 2);
 }
 
 #[cfg(test)]
 #[cfg(feature = "ternary-string")]
 #[test]
-fn test_each() {
+fn test_3bit_packed() {
     use crate::*;
-    let ternary = Ternary::parse("+0-");
-    assert_eq!(ternary.each(Digit::possibly).to_string(), "++-");
+
+    let ternary = ter("000++0--");
+    let packed = ternary.to_3bit_packed();
+    // 8 bytes of header, then 8 * 3 = 24 bits.
+    assert_eq!(packed.len(), 8 + 3);
+    assert_eq!(&packed[..8], &8u64.to_le_bytes());
+    assert_eq!(Ternary::from_3bit_packed(&packed), Ok(ternary));
+    for length in 0..20 {
+        for ternary in [Ternary::parse(&"+".repeat(length)), Ternary::parse(&"-0".repeat(length))] {
+            let packed = ternary.to_3bit_packed();
+            assert_eq!(packed.len(), 8 + (ternary.log() * 3).div_ceil(8));
+            assert_eq!(Ternary::from_3bit_packed(&packed), Ok(ternary));
+        }
+    }
+    assert_eq!(Ternary::from_3bit_packed(&ter("").to_3bit_packed()), Ok(ter("")));
+
+    // Missing header, missing data, and invalid 3 bits values.
+    assert_eq!(Ternary::from_3bit_packed(&[0; 7]), Err(ParseTernaryError));
+    assert_eq!(Ternary::from_3bit_packed(&[3, 0, 0, 0, 0, 0, 0, 0, 0]), Err(ParseTernaryError));
+    assert_eq!(Ternary::from_3bit_packed(&[1, 0, 0, 0, 0, 0, 0, 0, 0b011]), Err(ParseTernaryError));
+    assert_eq!(Ternary::from_3bit_packed(&[u8::MAX; 9]), Err(ParseTernaryError));
 }
 
 #[cfg(test)]
 #[cfg(feature = "ternary-string")]
 #[test]
-fn test_operations() {
-    fn test_ternary_eq(a: Ternary, b: &str) {
-        let repr = Ternary::parse(b);
-        assert_eq!(a.to_string(), repr.to_string());
-    }
-    fn test_binary_op(a: &Ternary, f: impl Fn(Digit, Digit) -> Digit, b: &Ternary, c: &str) {
-        test_ternary_eq(a.each_zip(f, b.clone()), c);
+fn test_index() {
+    use crate::*;
+
+    let ternary = ter("+++--+");
+    assert_eq!(ternary[1].to_char(), '-');
+    for i in 0..ternary.log() {
+        assert_eq!(&ternary[i], ternary.get_digit(i).unwrap());
     }
+    assert_eq!(ternary[5], Pos);
+}
 
-    use core::ops::{BitAnd, BitOr, BitXor, Mul, Not};
+#[cfg(test)]
+#[cfg(feature = "ternary-string")]
+#[test]
+#[should_panic(expected = "Ternary::index(): the index is 6 but the length is 6.")]
+fn test_index_out_of_range() {
+    use crate::*;
 
-    let short = Ternary::parse("-0+");
-    let long = Ternary::parse("---000+++");
-    let other = Ternary::parse("-0+-0+-0+");
+    let _ = ter("+++--+")[6];
+}
 
-    // K3
-    test_ternary_eq(short.each(Digit::not), "+0-");
-    test_binary_op(&long, Digit::bitand, &other, "----00-0+");
-    test_binary_op(&long, Digit::bitor, &other, "-0+00++++");
-    test_binary_op(&long, Digit::bitxor, &other, "-0+000+0-");
-    test_binary_op(&long, Digit::k3_equiv, &other, "+0-000-0+");
-    test_binary_op(&long, Digit::k3_imply, &other, "+++00+-0+");
+#[cfg(test)]
+#[cfg(feature = "ternary-string")]
+#[test]
+fn test_into_iter() {
+    use crate::*;
 
-    // HT
-    test_ternary_eq(short.each(Digit::ht_not), "+--");
-    test_binary_op(&long, Digit::ht_imply, &other, "+++-++-0+");
+    let ternary = ter("+0--");
+    let mut chars = String::new();
+    for digit in &ternary {
+        chars.push(digit.to_char());
+    }
+    assert_eq!(chars, "+0--");
+    assert_eq!((&ternary).into_iter().copied().collect::<Vec<_>>(), ternary.to_digit_slice());
+    assert_eq!(ternary.clone().into_iter().filter(|d| *d == Neg).count(), 2);
+    assert_eq!(ternary.clone().into_iter().collect::<Vec<_>>(), ternary.to_digit_slice());
+}
 
-    // BI3
-    test_binary_op(&long, Digit::bi3_and, &other, "-0-000-0+");
-    test_binary_op(&long, Digit::bi3_or, &other, "-0+000+0+");
-    test_binary_op(&long, Digit::bi3_imply, &other, "+0+000-0+");
+#[cfg(test)]
+#[cfg(feature = "ternary-string")]
+#[test]
+fn test_push_pop_extend() {
+    use crate::*;
 
-    // L3
-    test_ternary_eq(short.each(Digit::possibly), "-++");
-    test_ternary_eq(short.each(Digit::necessary), "--+");
-    test_ternary_eq(short.each(Digit::contingently), "-+-");
-    test_binary_op(&long, Digit::l3_imply, &other, "+++0++-0+");
+    let mut ternary = ter("+-");
+    for digit in [Neg, Zero, Pos] {
+        ternary.push(digit);
+        assert_eq!(ternary.pop(), Some(digit));
+    }
+    assert_eq!(ternary.to_string(), "+-");
+    assert_eq!(ternary.pop(), Some(Neg));
+    assert_eq!(ternary.pop(), Some(Pos));
+    assert_eq!(ternary.pop(), None);
 
-    // PARA / RM3
-    test_binary_op(&long, Digit::rm3_imply, &other, "+++-0+--+");
-    test_binary_op(&long, Digit::para_imply, &other, "+++-0+-0+");
+    ternary.extend(ter("+0-").into_iter().rev());
+    assert_eq!(ternary.to_string(), "-0+");
+    let other = ter("0+");
+    ternary.extend(&other);
+    assert_eq!(ternary, ter("-0+").concat(&other));
+    ternary.extend(core::iter::repeat_n(Pos, 2));
+    assert_eq!(ternary.to_string(), "-0+0+++");
+}
 
-    // Other operations
-    test_ternary_eq(short.each(Digit::post), "0+-");
-    test_ternary_eq(short.each(Digit::pre), "+-0");
-    test_ternary_eq(short.each(Digit::absolute_positive), "+0+");
-    test_ternary_eq(short.each(Digit::positive), "00+");
-    test_ternary_eq(short.each(Digit::not_negative), "0++");
-    test_ternary_eq(short.each(Digit::not_positive), "--0");
-    test_ternary_eq(short.each(Digit::negative), "-00");
-    test_ternary_eq(short.each(Digit::absolute_negative), "-0-");
+#[cfg(test)]
+#[cfg(feature = "ternary-string")]
+#[test]
+fn test_truncate_split_at() {
+    use crate::*;
 
-    test_binary_op(&long, Digit::mul, &other, "+0-000-0+");
+    let mut ternary = ter("+++--+");
+    ternary.truncate(3);
+    assert_eq!(ternary, ter("--+"));
+    assert_eq!(ternary, ter("+++--+").to_fixed_width_wrapping(3));
+    ternary.truncate(0);
+    assert_eq!(ternary.log(), 0);
+
+    let ternary = ter("+0-+0--+");
+    for index in 0..=ternary.log() {
+        let (high, low) = ternary.split_at(index);
+        assert_eq!(low.log(), index);
+        assert_eq!(high.concat(&low), ternary);
+    }
+    let (high, low) = ternary.split_at(3);
+    assert_eq!(high.to_string(), "+0-+0");
+    assert_eq!(low.to_string(), "--+");
 }
 
 #[cfg(test)]
 #[cfg(feature = "ternary-string")]
 #[test]
-fn test_from_str() {
-    use core::str::FromStr;
-
-    let ternary = Ternary::from_str("+-0").unwrap();
-    assert_eq!(ternary.to_string(), "+-0");
+#[should_panic(expected = "Ternary::split_at(): the index is 4 but the length is 3.")]
+fn test_split_at_out_of_range() {
+    let _ = crate::ter("+0-").split_at(4);
+}
 
-    assert!(Ternary::from_str("+-x").is_err());
+#[cfg(test)]
+#[cfg(feature = "ternary-string")]
+#[test]
+fn test_reversed() {
+    use crate::*;
 
-    #[cfg(feature = "tryte")]
-    {
-        let tryte = <crate::Tryte>::from_str("+-0").unwrap();
-        assert_eq!(tryte.to_string(), "000+-0");
-        assert!(<crate::Tryte>::from_str("+-x").is_err());
+    assert_eq!(ter("+0-").reversed(), ter("-0+"));
+    assert_ne!(ter("+--").reversed(), -&ter("+--"));
+    assert_eq!(ter("+-0++").reversed().reversed(), ter("+-0++"));
+    assert_eq!(ter("").reversed().log(), 0);
+    assert!(ter("+0+").is_palindrome());
+    assert_eq!(ter("+0+").reversed(), ter("+0+"));
+    for repr in ["+0-", "+--", "0+0+", "-+"] {
+        assert_eq!(ter(repr).is_palindrome(), ter(repr).reversed() == ter(repr));
     }
 }
 
 #[cfg(test)]
 #[cfg(feature = "ternary-string")]
 #[test]
-fn test_ordering() {
-    use crate::ter;
+fn test_from_str_radix() {
+    use crate::*;
 
-    assert!(ter("-+") < ter("0"));
-    assert!(ter("0") < ter("++"));
+    assert_eq!(Ternary::from_str_radix("ff", 16).unwrap().to_dec(), 255);
+    assert_eq!(Ternary::from_str_radix("FF", 16).unwrap().to_dec(), 255);
+    assert_eq!(Ternary::from_str_radix("-100", 10).unwrap().to_dec(), -100);
+    assert_eq!(Ternary::from_str_radix("+101", 2).unwrap().to_dec(), 5);
+    assert_eq!(Ternary::from_str_radix("zz", 36).unwrap().to_dec(), 1295);
+    assert_eq!(Ternary::from_str_radix("0", 7).unwrap().to_string(), "0");
+    assert_eq!(Ternary::from_str_radix("-12", 3), Ok(Ternary::from_unbalanced("-12")));
+
+    assert_eq!(Ternary::from_str_radix("", 10), Err(ParseTernaryError));
+    assert_eq!(Ternary::from_str_radix("g", 16), Err(ParseTernaryError));
+    assert_eq!(Ternary::from_str_radix("+-0", 10), Err(ParseTernaryError));
+    assert_eq!(Ternary::from_str_radix("10", 1), Err(ParseTernaryError));
+    assert_eq!(Ternary::from_str_radix("10", 37), Err(ParseTernaryError));
+    assert_eq!(Ternary::from_str_radix("9223372036854775808", 10), Err(ParseTernaryError));
 }
 
 #[cfg(test)]
 #[cfg(feature = "ternary-string")]
 #[test]
-fn test_ordering_additional() {
-    use crate::ter;
+fn test_f64_conversions() {
+    use crate::*;
 
-    // Validate comparisons across a range of values
-    assert!(ter("--") < ter("-0"));
-    assert!(ter("-0") < ter("-"));
-    assert!(ter("+") < ter("+-"));
-    assert!(ter("+-") < ter("++"));
+    assert_eq!(Ternary::from_f64(5.4).to_dec(), 5);
+    assert_eq!(Ternary::from_f64(5.5).to_dec(), 6);
+    assert_eq!(Ternary::from_f64(-5.4).to_dec(), -5);
+    assert_eq!(Ternary::from_f64(-5.5).to_dec(), -6);
+    assert_eq!(Ternary::from_f64(0.49999999999999994).to_dec(), 0);
+    assert_eq!(Ternary::from_f64(-0.0).to_string(), "0");
+    assert_eq!(Ternary::from_f64(i64::MIN as f64), Ternary::from_dec(i64::MIN));
 
-    // Sorting should arrange values by their decimal value
-    let mut values = vec![ter("+"), ter("--"), ter("+-"), ter("-"), ter("0"), ter("-0"), ter("++")];
-    values.sort();
-    let expected = vec![ter("--"), ter("-0"), ter("-"), ter("0"), ter("+"), ter("+-"), ter("++")];
-    assert_eq!(values, expected);
+    // Out of the range of `i64`, 2^64 and -2^70.
+    assert_eq!(Ternary::from_f64(18_446_744_073_709_551_616.0).to_f64(), 2f64.powi(64));
+    let big = Ternary::from_f64(-2f64.powi(70));
+    let half = Ternary::from_f64(2f64.powi(35));
+    assert_eq!(big, -&half.mul_digits(&half));
+    let huge = Ternary::from_f64(1e300);
+    assert!((huge.to_f64() - 1e300).abs() / 1e300 < 1e-12);
+    assert_eq!(Ternary::from_f64(f64::MAX).log(), Ternary::from_f64(-f64::MAX).log());
+
+    for value in [0, 1, -1, 42, -1_000_000, i64::MAX / 3] {
+        assert_eq!(Ternary::from_dec(value).to_f64(), value as f64);
+    }
+    let long = Ternary::parse(&"+".repeat(50));
+    let expected = (3f64.powi(50) - 1.0) / 2.0;
+    assert!(long.to_f64().is_finite());
+    assert!((long.to_f64() - expected).abs() / expected < 1e-12);
+    assert!(Ternary::parse(&"+".repeat(700)).to_f64().is_infinite());
 }
 
 #[cfg(test)]
 #[cfg(feature = "ternary-string")]
 #[test]
-fn test_iterators() {
+#[should_panic(expected = "Ternary::from_f64(): Invalid value: NaN")]
+fn test_from_f64_nan() {
+    let _ = crate::Ternary::from_f64(f64::NAN);
+}
+
+#[cfg(test)]
+#[cfg(feature = "ternary-string")]
+#[test]
+fn test_grouped_string() {
     use crate::*;
 
-    let ternary = Ternary::parse("+0-");
-    let expected = vec![Pos, Zero, Neg];
-    assert_eq!(ternary.iter().cloned().collect::<Vec<_>>(), expected);
-    let collected: Vec<Digit> = Ternary::parse("+0-").into_iter().collect();
-    assert_eq!(collected, expected);
+    let ternary = ter("+-0+-0+-");
+    assert_eq!(ternary.to_grouped_string(0, '_'), "+-0+-0+-");
+    assert_eq!(ternary.to_grouped_string(1, '.'), "+.-.0.+.-.0.+.-");
+    assert_eq!(ternary.to_grouped_string(2, '_'), "+-_0+_-0_+-");
+    assert_eq!(ternary.to_grouped_string(3, '_'), "+-_0+-_0+-");
+    assert_eq!(ternary.to_grouped_string(4, ' '), "+-0+ -0+-");
+    assert_eq!(ternary.to_grouped_string(8, '_'), "+-0+-0+-");
+    assert_eq!(ternary.to_grouped_string(20, '_'), "+-0+-0+-");
+    assert_eq!(ter("").to_grouped_string(3, '_'), "0");
+    assert_eq!(ter("-").to_grouped_string(1, '_'), "-");
 }
@@ -0,0 +1,193 @@
+//! This module provides tools to work with the three-valued logics implemented by [Digit].
+//!
+//! # Overview
+//!
+//! - [LogicSystem]: selects a logic at runtime and dispatches the `imply`, `and`, `or` and `not`
+//!   operations to the corresponding [Digit] methods.
+//! - [is_tautology]: checks whether a formula always evaluates to `Pos`.
+
+use crate::Digit;
+use alloc::vec;
+
+/// A three-valued logic system.
+///
+/// Each variant dispatches the logical operations to the [Digit] methods of the corresponding logic.
+///
+/// | System | `imply`               | `and`                | `or`                | `not`             |
+/// |--------|-----------------------|----------------------|---------------------|-------------------|
+/// | `K3`   | [Digit::k3_imply]     | `Digit::bitand`      | `Digit::bitor`      | `Digit::not`      |
+/// | `BI3`  | [Digit::bi3_imply]    | [Digit::bi3_and]     | [Digit::bi3_or]     | `Digit::not`      |
+/// | `L3`   | [Digit::l3_imply]     | `Digit::bitand`      | `Digit::bitor`      | `Digit::not`      |
+/// | `RM3`  | [Digit::rm3_imply]    | `Digit::bitand`      | `Digit::bitor`      | `Digit::not`      |
+/// | `HT`   | [Digit::ht_imply]     | `Digit::bitand`      | `Digit::bitor`      | [Digit::ht_not]   |
+///
+/// # Example
+///
+/// ```
+/// use balanced_ternary::logic::LogicSystem;
+/// use balanced_ternary::Digit::{Neg, Pos, Zero};
+///
+/// let logic = LogicSystem::L3;
+/// assert_eq!(logic.imply(Zero, Zero), Pos);
+/// assert_eq!(LogicSystem::K3.imply(Zero, Zero), Zero);
+/// assert_eq!(LogicSystem::HT.not(Zero), Neg);
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum LogicSystem {
+    /// Kleene logic.
+    K3,
+    /// Bochvar's internal three-valued logic.
+    BI3,
+    /// Łukasiewicz logic.
+    L3,
+    /// Routley-Meyer paraconsistent logic.
+    RM3,
+    /// Heyting logic-inspired ternary system.
+    HT,
+}
+
+impl LogicSystem {
+    /// Every available logic system.
+    pub const ALL: [LogicSystem; 5] = [
+        LogicSystem::K3,
+        LogicSystem::BI3,
+        LogicSystem::L3,
+        LogicSystem::RM3,
+        LogicSystem::HT,
+    ];
+
+    /// Performs the implication `a -> b` in this logic system.
+    pub fn imply(self, a: Digit, b: Digit) -> Digit {
+        match self {
+            LogicSystem::K3 => a.k3_imply(b),
+            LogicSystem::BI3 => a.bi3_imply(b),
+            LogicSystem::L3 => a.l3_imply(b),
+            LogicSystem::RM3 => a.rm3_imply(b),
+            LogicSystem::HT => a.ht_imply(b),
+        }
+    }
+
+    /// Performs the conjunction `a AND b` in this logic system.
+    pub fn and(self, a: Digit, b: Digit) -> Digit {
+        match self {
+            LogicSystem::BI3 => a.bi3_and(b),
+            _ => a & b,
+        }
+    }
+
+    /// Performs the disjunction `a OR b` in this logic system.
+    pub fn or(self, a: Digit, b: Digit) -> Digit {
+        match self {
+            LogicSystem::BI3 => a.bi3_or(b),
+            _ => a | b,
+        }
+    }
+
+    /// Performs the negation `NOT a` in this logic system.
+    pub fn not(self, a: Digit) -> Digit {
+        match self {
+            LogicSystem::HT => a.ht_not(),
+            _ => !a,
+        }
+    }
+}
+
+/// Checks whether a ternary formula is a tautology.
+///
+/// Enumerates all the `3^vars` assignments of `vars` variables and evaluates the formula `f`
+/// for each of them.
+///
+/// # Arguments
+///
+/// * `vars` - The number of variables of the formula.
+/// * `f` - The formula, taking a slice of exactly `vars` digits.
+///
+/// # Returns
+///
+/// `true` if `f` yields `Pos` for every assignment, `false` otherwise.
+///
+/// # Example
+///
+/// ```
+/// use balanced_ternary::logic::is_tautology;
+///
+/// // a -> a
+/// assert!(is_tautology(1, |v| v[0].l3_imply(v[0])));
+/// assert!(!is_tautology(1, |v| v[0].k3_imply(v[0])));
+/// ```
+pub fn is_tautology(vars: usize, f: impl Fn(&[Digit]) -> Digit) -> bool {
+    let mut assignment = vec![Digit::Neg; vars];
+    loop {
+        if f(&assignment) != Digit::Pos {
+            return false;
+        }
+        // Moves to the next assignment, like an odometer.
+        let mut i = 0;
+        loop {
+            if i == vars {
+                return true;
+            }
+            if assignment[i] == Digit::Pos {
+                assignment[i] = Digit::Neg;
+                i += 1;
+            } else {
+                assignment[i] = assignment[i].post();
+                break;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn test_logic_system() {
+    use crate::Digit::{Neg, Pos, Zero};
+
+    let digits = [Neg, Zero, Pos];
+    for system in LogicSystem::ALL {
+        for a in digits {
+            let not = match system {
+                LogicSystem::HT => a.ht_not(),
+                _ => -a,
+            };
+            assert_eq!(system.not(a), not);
+            for b in digits {
+                let (imply, and, or) = match system {
+                    LogicSystem::K3 => (a.k3_imply(b), a & b, a | b),
+                    LogicSystem::BI3 => (a.bi3_imply(b), a.bi3_and(b), a.bi3_or(b)),
+                    LogicSystem::L3 => (a.l3_imply(b), a & b, a | b),
+                    LogicSystem::RM3 => (a.rm3_imply(b), a & b, a | b),
+                    LogicSystem::HT => (a.ht_imply(b), a & b, a | b),
+                };
+                assert_eq!(system.imply(a, b), imply);
+                assert_eq!(system.and(a, b), and);
+                assert_eq!(system.or(a, b), or);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn test_is_tautology() {
+    use crate::Digit::{Neg, Pos};
+
+    // a -> a holds in L3, RM3 and HT, but not in K3 and BI3 where 0 -> 0 is 0.
+    assert!(is_tautology(1, |v| v[0].l3_imply(v[0])));
+    assert!(is_tautology(1, |v| v[0].ht_imply(v[0])));
+    assert!(!is_tautology(1, |v| v[0].k3_imply(v[0])));
+    assert!(!is_tautology(1, |v| LogicSystem::BI3.imply(v[0], v[0])));
+
+    // a -> b is never a tautology.
+    for system in LogicSystem::ALL {
+        assert!(!is_tautology(2, |v| system.imply(v[0], v[1])));
+    }
+
+    // (a AND b) -> a holds in L3.
+    let l3 = LogicSystem::L3;
+    assert!(is_tautology(2, |v| l3.imply(l3.and(v[0], v[1]), v[0])));
+
+    // Every assignment is visited.
+    assert!(!is_tautology(3, |v| if v == [Pos, Pos, Neg] { Neg } else { Pos }));
+    assert!(is_tautology(0, |_| Pos));
+}
@@ -24,14 +24,16 @@
 //! - `Sub<&Ternary>` for `&Ternary`: Subtracts one `Ternary` from another and returns a new `Ternary`. Panics on overflow.
 //! - `Mul<&Ternary>` for `&Ternary`: Multiplies two `Ternary` values and returns a new `Ternary`. Panics on overflow.
 //! - `Div<&Ternary>` for `&Ternary`: Divides one `Ternary` by another and returns a new `Ternary`. Panics on overflow or division by zero.
+//! - `Rem<&Ternary>` for `&Ternary`: Computes the remainder of the division of one `Ternary` by another. Panics on division by zero.
 //! - `BitAnd<&Ternary>` for `&Ternary`: Computes the bitwise AND operation on two `Ternary` operands.
 //! - `BitOr<&Ternary>` for `&Ternary`: Computes the bitwise OR operation on two `Ternary` operands.
 //! - `BitXor<&Ternary>` for `&Ternary`: Computes the bitwise XOR operation on two `Ternary` operands.
+//! - `BitAnd`, `BitOr` and `BitXor` are also implemented for the owned and mixed forms (`Ternary & Ternary`, `Ternary & &Ternary`, ...).
 
 use crate::concepts::DigitOperate;
 use crate::{Digit, Ternary};
 use alloc::vec;
-use core::ops::{Add, BitAnd, BitOr, BitXor, Div, Mul, Neg, Not, Sub, Shl, Shr};
+use core::ops::{Add, BitAnd, BitOr, BitXor, Div, Mul, Neg, Not, Rem, Sub, Shl, Shr};
 
 impl Neg for &Ternary {
     type Output = Ternary;
@@ -119,6 +121,18 @@ impl Div<&Ternary> for &Ternary {
     }
 }
 
+impl Rem<&Ternary> for &Ternary {
+    type Output = Ternary;
+
+    /// Computes the remainder of the division, which has the sign of the dividend (as `%` for
+    /// Rust integers).
+    ///
+    /// It is computed with [Ternary::long_div], so it does not overflow.
+    fn rem(self, rhs: &Ternary) -> Self::Output {
+        self.long_div(rhs).1
+    }
+}
+
 impl BitAnd<&Ternary> for &Ternary {
     type Output = Ternary;
 
@@ -143,13 +157,85 @@ impl BitXor<&Ternary> for &Ternary {
     }
 }
 
+impl BitAnd<Ternary> for &Ternary {
+    type Output = Ternary;
+
+    fn bitand(self, rhs: Ternary) -> Self::Output {
+        self.each_zip(Digit::bitand, rhs)
+    }
+}
+
+impl BitAnd<&Ternary> for Ternary {
+    type Output = Ternary;
+
+    fn bitand(self, rhs: &Ternary) -> Self::Output {
+        &self & rhs
+    }
+}
+
+impl BitAnd<Ternary> for Ternary {
+    type Output = Ternary;
+
+    fn bitand(self, rhs: Ternary) -> Self::Output {
+        &self & rhs
+    }
+}
+
+impl BitOr<Ternary> for &Ternary {
+    type Output = Ternary;
+
+    fn bitor(self, rhs: Ternary) -> Self::Output {
+        self.each_zip(Digit::bitor, rhs)
+    }
+}
+
+impl BitOr<&Ternary> for Ternary {
+    type Output = Ternary;
+
+    fn bitor(self, rhs: &Ternary) -> Self::Output {
+        &self | rhs
+    }
+}
+
+impl BitOr<Ternary> for Ternary {
+    type Output = Ternary;
+
+    fn bitor(self, rhs: Ternary) -> Self::Output {
+        &self | rhs
+    }
+}
+
+impl BitXor<Ternary> for &Ternary {
+    type Output = Ternary;
+
+    fn bitxor(self, rhs: Ternary) -> Self::Output {
+        self.each_zip(Digit::bitxor, rhs)
+    }
+}
+
+impl BitXor<&Ternary> for Ternary {
+    type Output = Ternary;
+
+    fn bitxor(self, rhs: &Ternary) -> Self::Output {
+        &self ^ rhs
+    }
+}
+
+impl BitXor<Ternary> for Ternary {
+    type Output = Ternary;
+
+    fn bitxor(self, rhs: Ternary) -> Self::Output {
+        &self ^ rhs
+    }
+}
+
 impl Shl<usize> for &Ternary {
     type Output = Ternary;
 
     fn shl(self, rhs: usize) -> Self::Output {
         let mut repr = Ternary::new(vec![]);
         repr.digits.extend(self.digits.iter().cloned());
-        repr.digits.extend(core::iter::repeat(Digit::Zero).take(rhs));
+        repr.digits.extend(core::iter::repeat_n(Digit::Zero, rhs));
         repr
     }
 }
@@ -231,3 +317,38 @@ fn test_shift_ops() {
     let zero = &t >> 5;
     assert_eq!(zero.to_string(), "0");
 }
+
+#[cfg(test)]
+#[test]
+fn test_rem_op() {
+    for a in [-40, -17, -1, 0, 1, 5, 17, 40] {
+        for b in [-7, -3, 1, 2, 13] {
+            let rem = &Ternary::from_dec(a) % &Ternary::from_dec(b);
+            assert_eq!(rem.to_dec(), a % b);
+        }
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn test_owned_bitwise_ops() {
+    let a = Ternary::parse("+0-+");
+    let b = Ternary::parse("-++0");
+    let and = &a & &b;
+    let or = &a | &b;
+    let xor = &a ^ &b;
+
+    assert_eq!(a.clone() & b.clone(), and);
+    assert_eq!(a.clone() & &b, and);
+    assert_eq!(&a & b.clone(), and);
+    assert_eq!(a.clone() | b.clone(), or);
+    assert_eq!(a.clone() | &b, or);
+    assert_eq!(&a | b.clone(), or);
+    assert_eq!(a.clone() ^ b.clone(), xor);
+    assert_eq!(a.clone() ^ &b, xor);
+    assert_eq!(&a ^ b.clone(), xor);
+
+    assert_eq!(Ternary::parse("++00") & Ternary::parse("0+00"), Ternary::parse("0+00"));
+    assert_eq!(Ternary::parse("+000") | Ternary::parse("000+"), Ternary::parse("+00+"));
+    assert_eq!((a & b).to_dec(), Ternary::parse("-0-0").to_dec());
+}
@@ -1,10 +1,30 @@
 use crate::concepts::DigitOperate;
-use crate::{Digit, Ternary};
+use crate::{Digit, RangeError, Ternary};
 use alloc::string::ToString;
 use alloc::vec::Vec;
 use core::fmt::Display;
 use core::ops::{Add, BitAnd, BitOr, BitXor, Div, Mul, Neg, Sub};
 
+/// Error returned by the fallible constructors of the storage types.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StoreError {
+    /// The value is out of the range of the storage type.
+    OutOfRange,
+    /// The `Ternary` has too many digits for the storage type.
+    TooLong,
+}
+
+impl Display for StoreError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            StoreError::OutOfRange => write!(f, "value out of range for the storage type"),
+            StoreError::TooLong => write!(f, "too many digits for the storage type"),
+        }
+    }
+}
+
+impl core::error::Error for StoreError {}
+
 /// A struct to store 5 ternary digits (~7.8 bits) value into one byte.
 ///
 /// `TritsChunks` helps store ternary numbers into a compact memory structure.
@@ -24,6 +44,7 @@ impl TritsChunk {
     /// # Panics
     ///
     /// This function panics if the input value is out of the valid range `-121..=121`.
+    /// Use [TritsChunk::try_from_dec] for a non-panicking conversion.
     ///
     /// # Example
     ///
@@ -34,10 +55,31 @@ impl TritsChunk {
     /// assert_eq!(chunk.to_dec(), 42);
     /// ```
     pub fn from_dec(from: i8) -> Self {
+        match Self::try_from_dec(from) {
+            Ok(chunk) => chunk,
+            Err(_) => panic!("TritsChunk::from_dec(): Invalid value: {}", from),
+        }
+    }
+
+    /// Tries to create a `TritsChunk` from a given decimal value.
+    ///
+    /// # Errors
+    ///
+    /// Returns [StoreError::OutOfRange] if the value is out of the valid range `-121..=121`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use balanced_ternary::{StoreError, TritsChunk};
+    ///
+    /// assert_eq!(TritsChunk::try_from_dec(42).unwrap().to_dec(), 42);
+    /// assert_eq!(TritsChunk::try_from_dec(122), Err(StoreError::OutOfRange));
+    /// ```
+    pub fn try_from_dec(from: i8) -> Result<Self, StoreError> {
         if !(-121..=121).contains(&from) {
-            panic!("TritsChunk::from_dec(): Invalid value: {}", from);
+            return Err(StoreError::OutOfRange);
         }
-        Self(from)
+        Ok(Self(from))
     }
 
     /// Converts the `TritsChunk` into its decimal representation.
@@ -127,7 +169,7 @@ impl TritsChunk {
     ///
     /// # Panics
     ///
-    /// This function panics if the provided `ternary` value has a logarithmic length greater than 5,
+    /// This function panics if the provided `ternary` value has more than 5 significant digits,
     /// indicating that it cannot be represented by a single `TritsChunk`.
     ///
     /// # Example
@@ -140,13 +182,39 @@ impl TritsChunk {
     /// assert_eq!(chunk.to_dec(), 42);
     /// ```
     pub fn from_ternary(ternary: Ternary) -> Self {
-        if ternary.log() > 5 {
-            panic!(
+        match Self::try_from_ternary(&ternary) {
+            Ok(chunk) => chunk,
+            Err(_) => panic!(
                 "TritsChunk::from_ternary(): Ternary is too long: {}",
                 ternary.to_string()
-            );
+            ),
+        }
+    }
+
+    /// Tries to create a `TritsChunk` from a given `Ternary` value.
+    ///
+    /// Leading `Zero` digits are ignored, as with [Ter40::try_from_ternary].
+    ///
+    /// # Errors
+    ///
+    /// Returns [StoreError::TooLong] if the `Ternary` has more than 5 significant digits.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use balanced_ternary::{StoreError, Ternary, TritsChunk};
+    ///
+    /// let chunk = TritsChunk::try_from_ternary(&Ternary::from_dec(42)).unwrap();
+    /// assert_eq!(chunk.to_dec(), 42);
+    /// let too_long = Ternary::parse("+00000");
+    /// assert_eq!(TritsChunk::try_from_ternary(&too_long), Err(StoreError::TooLong));
+    /// ```
+    pub fn try_from_ternary(ternary: &Ternary) -> Result<Self, StoreError> {
+        let significant = ternary.iter().skip_while(|d| **d == Digit::Zero).count();
+        if significant > 5 {
+            return Err(StoreError::TooLong);
         }
-        Self(ternary.to_dec() as i8)
+        Ok(Self(ternary.iter().fold(0, |acc, d| acc * 3 + d.to_i8())))
     }
 }
 
@@ -162,6 +230,9 @@ pub struct DataTernary {
 }
 
 impl DataTernary {
+    /// The number of chunks of a record in the format of [DataTernary::pack_slice].
+    const RECORD_CHUNKS: usize = 9;
+
     /// Creates a new instance of `DataTernary` from a given `Ternary` value.
     ///
     /// This method ensures that the total number of ternary digits is a multiple of 5
@@ -201,8 +272,8 @@ impl DataTernary {
     ///
     /// This function iterates over all the `TritsChunk` instances in the `DataTernary`,
     /// extracts their ternary representations, and reconstructs them into the full
-    /// `Ternary` value. The resulting `Ternary` value may be trimmed to remove
-    /// any leading zeroes in its ternary digit representation.
+    /// `Ternary` value. The resulting `Ternary` value is always trimmed: use
+    /// [DataTernary::to_ternary_padded] to keep the stored width.
     ///
     /// # Returns
     ///
@@ -221,11 +292,37 @@ impl DataTernary {
     pub fn to_ternary(&self) -> Ternary {
         let mut digits = Vec::new();
         for chunk in &self.chunks {
-            digits.extend(chunk.to_ternary().to_digit_slice());
+            digits.extend(chunk.to_fixed_ternary().to_digit_slice());
         }
         Ternary::new(digits).trim()
     }
 
+    /// Converts the `DataTernary` into a `Ternary` with its full stored width.
+    ///
+    /// Unlike [DataTernary::to_ternary], the leading zeroes are kept: the result has exactly
+    /// 5 digits per [TritsChunk].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use balanced_ternary::{DataTernary, Ternary};
+    ///
+    /// let data_ternary = DataTernary::from_dec(42);
+    /// assert_eq!(data_ternary.to_ternary().to_string(), "+---0");
+    /// assert_eq!(data_ternary.to_ternary_padded().to_string(), "+---0");
+    ///
+    /// let data_ternary = DataTernary::from_dec(-7);
+    /// assert_eq!(data_ternary.to_ternary().to_string(), "-+-");
+    /// assert_eq!(data_ternary.to_ternary_padded().to_string(), "00-+-");
+    /// ```
+    pub fn to_ternary_padded(&self) -> Ternary {
+        let mut digits = Vec::with_capacity(self.chunks.len() * 5);
+        for chunk in &self.chunks {
+            digits.extend(chunk.to_fixed_ternary().to_digit_slice());
+        }
+        Ternary::new(digits)
+    }
+
     /// Converts the `DataTernary` into its fixed-length `Ternary` representation.
     ///
     /// This method iterates over all the `TritsChunk` instances in the `DataTernary` and
@@ -278,6 +375,56 @@ impl DataTernary {
         self.to_ternary().trim().to_digit_slice().to_vec()
     }
 
+    /// Returns the [Digit] at `index`, or `None` if `index` is beyond the stored chunks.
+    ///
+    /// Digits are indexed **from the right**, like [Ternary::get_digit]. Only the
+    /// [TritsChunk] holding the digit is decoded, so this does not depend on the length of
+    /// the `DataTernary`. The leading `Zero` digits of the most significant chunk are readable.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use balanced_ternary::{DataTernary, Digit::{Neg, Pos, Zero}};
+    ///
+    /// let data = DataTernary::from_dec(42); // +---0
+    /// assert_eq!(data.digit_at(0), Some(Zero));
+    /// assert_eq!(data.digit_at(1), Some(Neg));
+    /// assert_eq!(data.digit_at(4), Some(Pos));
+    /// assert_eq!(data.digit_at(5), None);
+    /// ```
+    pub fn digit_at(&self, index: usize) -> Option<Digit> {
+        let chunk = self.chunks.len().checked_sub(index / 5 + 1)?;
+        Some(self.chunks[chunk].to_digits()[4 - index % 5])
+    }
+
+    /// Sets the [Digit] at `index` (**from the right**) to `value`.
+    ///
+    /// Only the [TritsChunk] holding the digit is decoded and re-encoded. If `index` is beyond
+    /// the stored chunks, `Zero` chunks are first added on the most significant side.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use balanced_ternary::{DataTernary, Digit::{Neg, Pos}};
+    ///
+    /// let mut data = DataTernary::from_dec(42);
+    /// data.set_digit_at(0, Pos);
+    /// assert_eq!(data.to_dec(), 43);
+    /// data.set_digit_at(6, Neg);
+    /// assert_eq!(data.to_dec(), 43 - 729);
+    /// ```
+    pub fn set_digit_at(&mut self, index: usize, value: Digit) {
+        let needed = index / 5 + 1;
+        if needed > self.chunks.len() {
+            let missing = needed - self.chunks.len();
+            self.chunks.splice(0..0, core::iter::repeat_n(TritsChunk(0), missing));
+        }
+        let chunk = self.chunks.len() - needed;
+        let mut digits = self.chunks[chunk].to_digits();
+        digits[4 - index % 5] = value;
+        self.chunks[chunk] = TritsChunk::from_ternary(Ternary::new(digits));
+    }
+
     /// Converts a decimal number into a `DataTernary` structure.
     ///
     /// This method takes a signed 64-bit integer as input and converts it into a
@@ -327,6 +474,240 @@ impl DataTernary {
     pub fn to_dec(&self) -> i64 {
         self.to_ternary().to_dec()
     }
+
+    /// Converts a `DataTernary` into its decimal representation, checking for overflows.
+    ///
+    /// The value is computed chunk by chunk, without building the `Ternary` first.
+    ///
+    /// # Returns
+    ///
+    /// * `Option<i64>` - The decimal value, or `None` if it does not fit into an `i64`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use balanced_ternary::{DataTernary, Ternary};
+    ///
+    /// assert_eq!(DataTernary::from_dec(-42).checked_to_dec(), Some(-42));
+    /// let long = DataTernary::from_ternary(Ternary::parse(&"+".repeat(45)));
+    /// assert_eq!(long.checked_to_dec(), None);
+    /// ```
+    pub fn checked_to_dec(&self) -> Option<i64> {
+        let value = self.chunks.iter().try_fold(0i128, |value, chunk| {
+            value.checked_mul(243)?.checked_add(chunk.to_dec() as i128)
+        })?;
+        i64::try_from(value).ok()
+    }
+
+    /// Converts a slice of decimal numbers into a vector of `DataTernary`, one per value.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use balanced_ternary::DataTernary;
+    ///
+    /// let column = DataTernary::from_dec_slice(&[42, -5, 0]);
+    /// assert_eq!(column.len(), 3);
+    /// assert_eq!(column[1].to_dec(), -5);
+    /// ```
+    pub fn from_dec_slice(values: &[i64]) -> Vec<DataTernary> {
+        values.iter().map(|value| Self::from_dec(*value)).collect()
+    }
+
+    /// Packs a slice of decimal numbers into a single `DataTernary`.
+    ///
+    /// # Record format
+    ///
+    /// Each value is stored as a fixed-size record of 9 [TritsChunk]s (45 trits, 9 bytes),
+    /// which is enough to hold any `i64`. The records follow each other in the order of the slice,
+    /// so a packed `DataTernary` always holds `9 * values.len()` chunks.
+    ///
+    /// Use [DataTernary::unpack_slice] to get the values back.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use balanced_ternary::DataTernary;
+    ///
+    /// let packed = DataTernary::pack_slice(&[42, -5, i64::MAX]);
+    /// assert_eq!(packed.unpack_slice(), vec![42, -5, i64::MAX]);
+    /// ```
+    pub fn pack_slice(values: &[i64]) -> DataTernary {
+        let mut chunks = Vec::with_capacity(values.len() * Self::RECORD_CHUNKS);
+        for value in values {
+            let mut record = [TritsChunk::default(); Self::RECORD_CHUNKS];
+            let mut value = *value as i128;
+            for chunk in record.iter_mut().rev() {
+                let rem = (value + 121).rem_euclid(243) - 121;
+                *chunk = TritsChunk(rem as i8);
+                value = (value - rem) / 243;
+            }
+            chunks.extend(record);
+        }
+        Self { chunks }
+    }
+
+    /// Unpacks the decimal numbers stored by [DataTernary::pack_slice].
+    ///
+    /// # Panics
+    ///
+    /// This function panics if the number of chunks is not a multiple of the record size (9 chunks),
+    /// or if a record holds a value out of the `i64` range.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use balanced_ternary::DataTernary;
+    ///
+    /// let packed = DataTernary::pack_slice(&[1, 2, 3]);
+    /// assert_eq!(packed.unpack_slice(), vec![1, 2, 3]);
+    /// ```
+    pub fn unpack_slice(&self) -> Vec<i64> {
+        if self.chunks.len() % Self::RECORD_CHUNKS != 0 {
+            panic!(
+                "DataTernary::unpack_slice(): Invalid number of chunks: {}",
+                self.chunks.len()
+            );
+        }
+        self.chunks
+            .chunks(Self::RECORD_CHUNKS)
+            .map(|record| {
+                let value = record
+                    .iter()
+                    .fold(0i128, |acc, chunk| acc * 243 + chunk.0 as i128);
+                i64::try_from(value).unwrap_or_else(|_| {
+                    panic!("DataTernary::unpack_slice(): Record out of range: {}", value)
+                })
+            })
+            .collect()
+    }
+
+    /// Negates the `DataTernary` in place.
+    ///
+    /// Negating a balanced ternary number negates each of its digits, so each [TritsChunk]
+    /// is negated directly, without converting the `DataTernary` into a `Ternary`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use balanced_ternary::DataTernary;
+    ///
+    /// let mut data = DataTernary::from_dec(-1234);
+    /// data.neg_in_place();
+    /// assert_eq!(data.to_dec(), 1234);
+    /// ```
+    pub fn neg_in_place(&mut self) {
+        for chunk in self.chunks.iter_mut() {
+            chunk.0 = -chunk.0;
+        }
+    }
+
+    /// Adds a decimal value to the `DataTernary` in place.
+    ///
+    /// The `delta` is added chunk by chunk from the least significant [TritsChunk], and the
+    /// carry stops propagating as soon as it is zero. New chunks are only prepended when the
+    /// value outgrows the existing ones, and leading `Zero` chunks are dropped when it shrinks,
+    /// so the result equals the `DataTernary` built with [DataTernary::from_dec].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use balanced_ternary::DataTernary;
+    ///
+    /// let mut counter = DataTernary::from_dec(120);
+    /// counter.add_assign_dec(2);
+    /// assert_eq!(counter.to_dec(), 122);
+    /// counter.add_assign_dec(-1_000);
+    /// assert_eq!(counter.to_dec(), -878);
+    /// ```
+    pub fn add_assign_dec(&mut self, delta: i64) {
+        let mut carry = delta as i128;
+        for chunk in self.chunks.iter_mut().rev() {
+            if carry == 0 {
+                break;
+            }
+            let value = chunk.0 as i128 + carry;
+            let rem = (value + 121).rem_euclid(243) - 121;
+            chunk.0 = rem as i8;
+            carry = (value - rem) / 243;
+        }
+        let mut prefix = Vec::new();
+        while carry != 0 {
+            let rem = (carry + 121).rem_euclid(243) - 121;
+            prefix.push(TritsChunk(rem as i8));
+            carry = (carry - rem) / 243;
+        }
+        prefix.reverse();
+        self.chunks.splice(0..0, prefix);
+        let leading = self.chunks.iter().take_while(|chunk| chunk.0 == 0).count();
+        self.chunks.drain(..leading.min(self.chunks.len().saturating_sub(1)));
+    }
+
+    /// Returns the stored bytes of the `DataTernary`: one byte (a two's complement `i8`)
+    /// per [TritsChunk], most significant chunk first.
+    ///
+    /// Use [DataTernary::from_bytes] to read them back.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use balanced_ternary::DataTernary;
+    ///
+    /// let data = DataTernary::from_dec(-1000);
+    /// assert_eq!(data.to_bytes(), vec![252, 228]); // -4 * 243 - 28
+    /// ```
+    pub fn to_bytes(&self) -> Vec<u8> {
+        self.chunks.iter().map(|chunk| chunk.0 as u8).collect()
+    }
+
+    /// Rebuilds a `DataTernary` from the bytes returned by [DataTernary::to_bytes]: each byte
+    /// is a two's complement `i8` holding a [TritsChunk] of 5 trits, most significant chunk first.
+    ///
+    /// The result holds `bytes.len()` chunks, so `to_bytes` gives back the same bytes.
+    ///
+    /// # Errors
+    ///
+    /// Returns [StoreError::OutOfRange] if a byte is outside of the `-121..=121` range of a
+    /// [TritsChunk].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use balanced_ternary::{DataTernary, StoreError};
+    ///
+    /// let data = DataTernary::from_dec(-1000);
+    /// assert_eq!(DataTernary::from_bytes(&data.to_bytes()), Ok(data));
+    /// assert_eq!(DataTernary::from_bytes(&[0, 122]), Err(StoreError::OutOfRange));
+    /// ```
+    pub fn from_bytes(bytes: &[u8]) -> Result<DataTernary, StoreError> {
+        let chunks = bytes
+            .iter()
+            .map(|byte| match *byte as i8 {
+                value @ -121..=121 => Ok(TritsChunk(value)),
+                _ => Err(StoreError::OutOfRange),
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(Self { chunks })
+    }
+
+    /// Interprets a byte slice as packed trits, like [DataTernary::from_bytes].
+    ///
+    /// # Errors
+    ///
+    /// Returns a [RangeError] if a byte is outside of the `-121..=121` range of a [TritsChunk].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use balanced_ternary::DataTernary;
+    ///
+    /// let data = DataTernary::view_bytes(&[252, 228]).unwrap();
+    /// assert_eq!(data.to_dec(), -1000);
+    /// assert!(DataTernary::view_bytes(&[1, -122i8 as u8]).is_err());
+    /// ```
+    pub fn view_bytes(bytes: &[u8]) -> Result<DataTernary, RangeError> {
+        Self::from_bytes(bytes).map_err(|_| RangeError)
+    }
 }
 
 impl Display for DataTernary {
@@ -350,21 +731,153 @@ impl From<DataTernary> for Ternary {
     }
 }
 
+/// The digits of a `DataTernary` are all its stored digits, including the leading `Zero`
+/// digits of its most significant chunk, as displayed by `Display`.
+impl DigitOperate for DataTernary {
+    fn to_digits(&self) -> Vec<Digit> {
+        self.to_ternary_padded().to_digit_slice().to_vec()
+    }
+
+    fn digit(&self, index: usize) -> Option<Digit> {
+        self.digit_at(index)
+    }
+
+    fn each(&self, f: impl Fn(Digit) -> Digit) -> Self
+    where
+        Self: Sized,
+    {
+        Self::from_ternary(self.to_ternary_padded().each(f))
+    }
+
+    fn each_with(&self, f: impl Fn(Digit, Digit) -> Digit, other: Digit) -> Self
+    where
+        Self: Sized,
+    {
+        Self::from_ternary(self.to_ternary_padded().each_with(f, other))
+    }
+
+    fn each_zip(&self, f: impl Fn(Digit, Digit) -> Digit, other: Self) -> Self
+    where
+        Self: Sized,
+    {
+        Self::from_ternary(self.to_ternary_padded().each_zip(f, other.to_ternary_padded()))
+    }
+
+    fn each_zip_carry(&self, f: impl Fn(Digit, Digit, Digit) -> (Digit, Digit), other: Self) -> Self
+    where
+        Self: Sized,
+    {
+        Self::from_ternary(
+            self.to_ternary_padded()
+                .each_zip_carry(f, other.to_ternary_padded()),
+        )
+    }
+}
+
 /// A struct to store 40 ternary digits (~63.398 bits) value into one `i64`.
 #[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
 #[repr(transparent)]
 pub struct Ter40(i64);
 
 impl Ter40 {
+    /// The greatest value of a `Ter40`: 40 `Pos` digits.
+    const MAX_VALUE: i64 = (3_i64.pow(39) - 1) / 2 * 3 + 1;
+
+    /// The greatest `Ter40`: 40 `Pos` digits.
+    pub const MAX: Ter40 = Ter40(Self::MAX_VALUE);
+
+    /// The least `Ter40`: 40 `Neg` digits.
+    pub const MIN: Ter40 = Ter40(-Self::MAX_VALUE);
+
+    /// Creates a `Ter40` from a decimal value.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if the value does not fit into 40 digits.
+    /// Use [Ter40::try_from_dec] for a non-panicking conversion.
     pub fn from_dec(from: i64) -> Self {
-        Self(from)
+        match Self::try_from_dec(from) {
+            Ok(ter40) => ter40,
+            Err(_) => panic!("Ter40::from_dec(): Invalid value: {}", from),
+        }
+    }
+
+    /// Tries to create a `Ter40` from a decimal value.
+    ///
+    /// # Errors
+    ///
+    /// Returns [StoreError::OutOfRange] if the value does not fit into 40 digits, that is
+    /// out of the range `-6_078_832_729_528_464_400..=6_078_832_729_528_464_400`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use balanced_ternary::{StoreError, Ter40};
+    ///
+    /// assert_eq!(Ter40::try_from_dec(42).unwrap().to_dec(), 42);
+    /// assert_eq!(Ter40::try_from_dec(i64::MAX), Err(StoreError::OutOfRange));
+    /// ```
+    pub fn try_from_dec(from: i64) -> Result<Self, StoreError> {
+        if !(-Self::MAX_VALUE..=Self::MAX_VALUE).contains(&from) {
+            return Err(StoreError::OutOfRange);
+        }
+        Ok(Self(from))
     }
+
+    /// Returns the decimal value of the `Ter40`.
     pub fn to_dec(&self) -> i64 {
         self.0
     }
+
+    /// Creates a `Ter40` from a `Ternary`.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if the `Ternary` has more than 40 significant digits.
+    /// Use [Ter40::try_from_ternary] for a non-panicking conversion.
     pub fn from_ternary(ternary: Ternary) -> Self {
-        Self(ternary.to_dec())
+        match Self::try_from_ternary(&ternary) {
+            Ok(ter40) => ter40,
+            Err(_) => panic!(
+                "Ter40::from_ternary(): Ternary is too long: {}",
+                ternary.to_string()
+            ),
+        }
     }
+
+    /// Tries to create a `Ter40` from a `Ternary`.
+    ///
+    /// Leading `Zero` digits are ignored. Any `Ternary` with at most 40 significant digits
+    /// fits into the `i64` storage of a `Ter40`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [StoreError::TooLong] if the `Ternary` has more than 40 significant digits.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use balanced_ternary::{Ter40, Ternary};
+    ///
+    /// let ter40 = Ter40::try_from_ternary(&Ternary::from_dec(42)).unwrap();
+    /// assert_eq!(ter40.to_dec(), 42);
+    ///
+    /// let too_long = Ternary::parse(&"+".repeat(41));
+    /// assert!(Ter40::try_from_ternary(&too_long).is_err());
+    /// ```
+    pub fn try_from_ternary(ternary: &Ternary) -> Result<Self, StoreError> {
+        let significant = ternary.iter().skip_while(|d| **d == Digit::Zero).count();
+        if significant > 40 {
+            return Err(StoreError::TooLong);
+        }
+        Ok(Self(
+            ternary
+                .iter()
+                .fold(0, |acc, d| acc * 3 + d.to_i8() as i64),
+        ))
+    }
+
+    /// Converts the `Ter40` into a `Ternary` of exactly 40 digits.
     pub fn to_ternary(&self) -> Ternary {
         Ternary::from_dec(self.0).with_length(40)
     }
@@ -383,31 +896,31 @@ impl DigitOperate for Ter40 {
     where
         Self: Sized,
     {
-        Self(self.to_ternary().each(f).to_dec())
+        Self::from_ternary(self.to_ternary().each(f))
     }
 
     fn each_with(&self, f: impl Fn(Digit, Digit) -> Digit, other: Digit) -> Self
     where
         Self: Sized,
     {
-        Self(self.to_ternary().each_with(f, other).to_dec())
+        Self::from_ternary(self.to_ternary().each_with(f, other))
     }
 
     fn each_zip(&self, f: impl Fn(Digit, Digit) -> Digit, other: Self) -> Self
     where
         Self: Sized,
     {
-        Self(self.to_ternary().each_zip(f, other.to_ternary()).to_dec())
+        Self::from_ternary(self.to_ternary().each_zip(f, other.to_ternary()))
     }
 
     fn each_zip_carry(&self, f: impl Fn(Digit, Digit, Digit) -> (Digit, Digit), other: Self) -> Self
     where
         Self: Sized,
     {
-        Self(
+        Self::from_ternary(
             self.to_ternary()
                 .each_zip_carry(f, other.to_ternary())
-                .to_dec(),
+                .to_fixed_width_wrapping(40),
         )
     }
 }
@@ -421,19 +934,19 @@ impl Display for Ter40 {
 impl Add for Ter40 {
     type Output = Self;
     fn add(self, other: Self) -> Self::Output {
-        Self(self.0 + other.0)
+        Self::from_dec(self.0.checked_add(other.0).expect("Overflow in addition."))
     }
 }
 impl Sub for Ter40 {
     type Output = Self;
     fn sub(self, other: Self) -> Self::Output {
-        Self(self.0 - other.0)
+        Self::from_dec(self.0.checked_sub(other.0).expect("Overflow in subtraction."))
     }
 }
 impl Mul for Ter40 {
     type Output = Self;
     fn mul(self, other: Self) -> Self::Output {
-        Self(self.0 * other.0)
+        Self::from_dec(self.0.checked_mul(other.0).expect("Overflow in multiplication."))
     }
 }
 impl Div for Ter40 {
@@ -473,7 +986,7 @@ impl BitXor for Ter40 {
 
 impl From<i64> for Ter40 {
     fn from(value: i64) -> Self {
-        Self(value)
+        Self::from_dec(value)
     }
 }
 
@@ -495,25 +1008,640 @@ impl From<Ter40> for Ternary {
     }
 }
 
-#[cfg(test)]
-#[test]
-fn single_chunk_creation() {
-    use crate::Ternary;
-
-    let ternary = Ternary::parse("+-0-+");
-    let data = DataTernary::from_ternary(ternary.clone());
+/// A struct to store 80 ternary digits (~126.8 bits) value into one `i128`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+#[repr(transparent)]
+pub struct Ter80(i128);
 
-    assert_eq!(data.chunks.len(), 1);
-    assert_eq!(data.to_ternary(), ternary);
-}
+impl Ter80 {
+    /// The greatest value of a `Ter80`: 80 `Pos` digits.
+    const MAX_VALUE: i128 = (3_i128.pow(80) - 1) / 2;
 
-#[cfg(test)]
-#[test]
-fn round_trip() {
-    use crate::Ternary;
+    /// The greatest `Ter80`: 80 `Pos` digits.
+    pub const MAX: Ter80 = Ter80(Self::MAX_VALUE);
 
-    let ternary = Ternary::parse("+0-0++-");
-    let data = DataTernary::from_ternary(ternary.clone());
+    /// The least `Ter80`: 80 `Neg` digits.
+    pub const MIN: Ter80 = Ter80(-Self::MAX_VALUE);
 
+    /// Creates a `Ter80` from a decimal value.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if the value does not fit into 80 digits.
+    /// Use [Ter80::try_from_dec] for a non-panicking conversion.
+    pub fn from_dec(from: i128) -> Self {
+        match Self::try_from_dec(from) {
+            Ok(ter80) => ter80,
+            Err(_) => panic!("Ter80::from_dec(): Invalid value: {}", from),
+        }
+    }
+
+    /// Tries to create a `Ter80` from a decimal value.
+    ///
+    /// # Errors
+    ///
+    /// Returns [StoreError::OutOfRange] if the value does not fit into 80 digits, that is
+    /// out of the range `-(3^80 - 1) / 2..=(3^80 - 1) / 2`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use balanced_ternary::{StoreError, Ter80};
+    ///
+    /// assert_eq!(Ter80::try_from_dec(42).unwrap().to_dec(), 42);
+    /// assert_eq!(Ter80::try_from_dec(i128::MAX), Err(StoreError::OutOfRange));
+    /// ```
+    pub fn try_from_dec(from: i128) -> Result<Self, StoreError> {
+        if !(-Self::MAX_VALUE..=Self::MAX_VALUE).contains(&from) {
+            return Err(StoreError::OutOfRange);
+        }
+        Ok(Self(from))
+    }
+
+    /// Returns the decimal value of the `Ter80`.
+    pub fn to_dec(&self) -> i128 {
+        self.0
+    }
+
+    /// Creates a `Ter80` from a `Ternary`.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if the `Ternary` has more than 80 significant digits.
+    /// Use [Ter80::try_from_ternary] for a non-panicking conversion.
+    pub fn from_ternary(ternary: Ternary) -> Self {
+        match Self::try_from_ternary(&ternary) {
+            Ok(ter80) => ter80,
+            Err(_) => panic!(
+                "Ter80::from_ternary(): Ternary is too long: {}",
+                ternary.to_string()
+            ),
+        }
+    }
+
+    /// Tries to create a `Ter80` from a `Ternary`.
+    ///
+    /// Leading `Zero` digits are ignored.
+    ///
+    /// # Errors
+    ///
+    /// Returns [StoreError::TooLong] if the `Ternary` has more than 80 significant digits.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use balanced_ternary::{Ter80, Ternary};
+    ///
+    /// let max = Ternary::parse(&"+".repeat(80));
+    /// assert_eq!(Ter80::try_from_ternary(&max).unwrap(), Ter80::MAX);
+    ///
+    /// let too_long = Ternary::parse(&"+".repeat(81));
+    /// assert!(Ter80::try_from_ternary(&too_long).is_err());
+    /// ```
+    pub fn try_from_ternary(ternary: &Ternary) -> Result<Self, StoreError> {
+        let significant = ternary.iter().skip_while(|d| **d == Digit::Zero).count();
+        if significant > 80 {
+            return Err(StoreError::TooLong);
+        }
+        Ok(Self(
+            ternary
+                .iter()
+                .fold(0, |acc, d| acc * 3 + d.to_i8() as i128),
+        ))
+    }
+
+    /// Converts the `Ter80` into its 80 digits `Ternary` representation.
+    pub fn to_ternary(&self) -> Ternary {
+        let mut digits = Vec::with_capacity(80);
+        let mut value = self.0;
+        while value != 0 {
+            let digit = match value.rem_euclid(3) {
+                0 => Digit::Zero,
+                1 => Digit::Pos,
+                _ => Digit::Neg,
+            };
+            value = (value - digit.to_i8() as i128) / 3;
+            digits.push(digit);
+        }
+        digits.reverse();
+        Ternary::new(digits).with_length(80)
+    }
+}
+
+impl DigitOperate for Ter80 {
+    fn to_digits(&self) -> Vec<Digit> {
+        self.to_ternary().to_digits()
+    }
+
+    fn digit(&self, index: usize) -> Option<Digit> {
+        self.to_ternary().digit(index)
+    }
+
+    fn each(&self, f: impl Fn(Digit) -> Digit) -> Self
+    where
+        Self: Sized,
+    {
+        Self::from_ternary(self.to_ternary().each(f))
+    }
+
+    fn each_with(&self, f: impl Fn(Digit, Digit) -> Digit, other: Digit) -> Self
+    where
+        Self: Sized,
+    {
+        Self::from_ternary(self.to_ternary().each_with(f, other))
+    }
+
+    fn each_zip(&self, f: impl Fn(Digit, Digit) -> Digit, other: Self) -> Self
+    where
+        Self: Sized,
+    {
+        Self::from_ternary(self.to_ternary().each_zip(f, other.to_ternary()))
+    }
+
+    fn each_zip_carry(&self, f: impl Fn(Digit, Digit, Digit) -> (Digit, Digit), other: Self) -> Self
+    where
+        Self: Sized,
+    {
+        Self::from_ternary(
+            self.to_ternary()
+                .each_zip_carry(f, other.to_ternary())
+                .to_fixed_width_wrapping(80),
+        )
+    }
+}
+
+impl Display for Ter80 {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}", self.to_ternary())
+    }
+}
+
+impl Add for Ter80 {
+    type Output = Self;
+    fn add(self, other: Self) -> Self::Output {
+        Self::from_dec(self.0 + other.0)
+    }
+}
+impl Sub for Ter80 {
+    type Output = Self;
+    fn sub(self, other: Self) -> Self::Output {
+        Self::from_dec(self.0 - other.0)
+    }
+}
+impl Mul for Ter80 {
+    type Output = Self;
+    fn mul(self, other: Self) -> Self::Output {
+        Self::from_dec(self.0.checked_mul(other.0).expect("Overflow in multiplication."))
+    }
+}
+impl Div for Ter80 {
+    type Output = Self;
+    fn div(self, other: Self) -> Self::Output {
+        Self(self.0 / other.0)
+    }
+}
+
+impl Neg for Ter80 {
+    type Output = Self;
+    fn neg(self) -> Self::Output {
+        Self(-self.0)
+    }
+}
+
+impl BitAnd for Ter80 {
+    type Output = Self;
+    fn bitand(self, other: Self) -> Self::Output {
+        self.each_zip(Digit::bitand, other)
+    }
+}
+
+impl BitOr for Ter80 {
+    type Output = Self;
+    fn bitor(self, other: Self) -> Self::Output {
+        self.each_zip(Digit::bitor, other)
+    }
+}
+
+impl BitXor for Ter80 {
+    type Output = Self;
+    fn bitxor(self, other: Self) -> Self::Output {
+        self.each_zip(Digit::bitxor, other)
+    }
+}
+
+impl From<i128> for Ter80 {
+    fn from(value: i128) -> Self {
+        Self::from_dec(value)
+    }
+}
+
+impl From<Ter80> for i128 {
+    fn from(value: Ter80) -> Self {
+        value.0
+    }
+}
+
+impl From<Ter40> for Ter80 {
+    fn from(value: Ter40) -> Self {
+        Self(value.0 as i128)
+    }
+}
+
+impl From<Ternary> for Ter80 {
+    fn from(value: Ternary) -> Self {
+        Self::from_ternary(value)
+    }
+}
+
+impl From<Ter80> for Ternary {
+    fn from(value: Ter80) -> Self {
+        value.to_ternary()
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn single_chunk_creation() {
+    use crate::Ternary;
+
+    let ternary = Ternary::parse("+-0-+");
+    let data = DataTernary::from_ternary(ternary.clone());
+
+    assert_eq!(data.chunks.len(), 1);
     assert_eq!(data.to_ternary(), ternary);
 }
+
+#[cfg(test)]
+#[test]
+fn round_trip() {
+    use crate::Ternary;
+
+    let ternary = Ternary::parse("+0-0++-");
+    let data = DataTernary::from_ternary(ternary.clone());
+
+    assert_eq!(data.to_ternary(), ternary);
+}
+
+#[cfg(test)]
+#[test]
+fn ter40_checked_construction() {
+    use crate::Ternary;
+    use alloc::string::String;
+
+    let max = Ternary::parse(&"+".repeat(40));
+    assert_eq!(Ter40::try_from_ternary(&max).unwrap().to_ternary(), max);
+
+    let min = Ternary::parse(&"-".repeat(40));
+    assert_eq!(Ter40::try_from_ternary(&min).unwrap().to_ternary(), min);
+
+    let too_long = Ternary::parse(&"+".repeat(41));
+    assert_eq!(Ter40::try_from_ternary(&too_long), Err(StoreError::TooLong));
+
+    // Leading zeros are not significant.
+    let padded = Ternary::parse(&(String::from("00") + &"-".repeat(40)));
+    assert_eq!(Ter40::try_from_ternary(&padded).unwrap().to_ternary(), min);
+}
+
+#[cfg(test)]
+#[test]
+fn ter40_range() {
+    use crate::Ternary;
+
+    assert_eq!(Ter40::MAX.to_dec(), 6_078_832_729_528_464_400);
+    assert_eq!(Ter40::MIN.to_dec(), -6_078_832_729_528_464_400);
+    for value in [6_078_832_729_528_464_400, -6_078_832_729_528_464_400, 0, 42, -1_000_000] {
+        let ter40 = Ter40::from_dec(value);
+        assert_eq!(ter40.to_dec(), value);
+        assert_eq!(ter40.to_ternary().log(), 40);
+        assert_eq!(Ter40::from_ternary(ter40.to_ternary()), ter40);
+    }
+    assert_eq!(Ter40::MAX.to_ternary(), Ternary::parse(&"+".repeat(40)));
+    assert_eq!(Ter40::MIN.to_ternary(), Ternary::parse(&"-".repeat(40)));
+    assert_eq!(-Ter40::MAX, Ter40::MIN);
+    assert_eq!(Ter40::MAX + Ter40::MIN, Ter40::default());
+    assert_eq!(Ter40::MAX.each(Digit::neg), Ter40::MIN);
+    assert_eq!(Ter40::MAX.each_zip(Digit::bitand, Ter40::from_dec(-1)).to_dec(), -1);
+    assert_eq!(Ter40::try_from_dec(6_078_832_729_528_464_401), Err(StoreError::OutOfRange));
+}
+
+#[cfg(test)]
+#[test]
+#[should_panic(expected = "Ter40::from_dec(): Invalid value: 6078832729528464401")]
+fn ter40_from_dec_out_of_range() {
+    let _ = Ter40::from_dec(6_078_832_729_528_464_401);
+}
+
+#[cfg(test)]
+#[test]
+#[should_panic(expected = "Ter40::from_dec(): Invalid value")]
+fn ter40_add_overflow() {
+    let _ = Ter40::MAX + Ter40::from_dec(1);
+}
+
+#[cfg(test)]
+#[test]
+fn data_ternary_digit_operate() {
+    let data = DataTernary::from_dec(-7);
+    assert_eq!(DigitOperate::to_digits(&data).len(), 5);
+    assert_eq!(data.digit(1), Some(Digit::Pos));
+    assert_eq!(data.digit(5), None);
+    assert_eq!(data.each(Digit::neg), DataTernary::from_dec(7));
+    assert_eq!(data.each_with(Digit::bitor, Digit::Pos).to_dec(), 121);
+    let other = DataTernary::from_dec(1_000);
+    assert_eq!(
+        data.each_zip(Digit::bitor, other.clone()).to_ternary(),
+        data.to_ternary().each_zip(Digit::bitor, other.to_ternary()),
+    );
+}
+
+#[cfg(test)]
+#[test]
+fn digit_at() {
+    use crate::Ternary;
+
+    let ternary = Ternary::parse("+-0+--0+-0+0-+-0++");
+    let data = DataTernary::from_ternary(ternary.clone());
+    let digits = data.to_digits();
+    for index in [0, 1, 4, 5, 6, 9, 10, 14, 15, 17] {
+        assert_eq!(data.digit_at(index), Some(digits[digits.len() - 1 - index]));
+        assert_eq!(data.digit_at(index).as_ref(), ternary.get_digit(index));
+    }
+    // The padding of the most significant chunk, then beyond the chunks.
+    assert_eq!(data.digit_at(19), Some(Digit::Zero));
+    assert_eq!(data.digit_at(20), None);
+    assert_eq!(DataTernary::default().digit_at(0), None);
+}
+
+#[cfg(test)]
+#[test]
+fn set_digit_at() {
+    let mut data = DataTernary::from_dec(0);
+    data.set_digit_at(0, Digit::Pos);
+    assert_eq!(data.to_ternary().to_dec(), 1);
+    data.set_digit_at(4, Digit::Neg);
+    assert_eq!(data.to_ternary().to_dec(), 1 - 81);
+    data.set_digit_at(0, Digit::Zero);
+    assert_eq!(data.to_ternary().to_dec(), -81);
+
+    // Beyond the stored chunks, zero chunks are added.
+    data.set_digit_at(12, Digit::Pos);
+    assert_eq!(data.to_ternary().to_dec(), 531_441 - 81);
+    assert_eq!(data.to_bytes().len(), 3);
+    assert_eq!(data.digit_at(12), Some(Digit::Pos));
+    assert_eq!(data.digit_at(7), Some(Digit::Zero));
+
+    let mut empty = DataTernary::default();
+    empty.set_digit_at(5, Digit::Neg);
+    assert_eq!(empty.to_ternary().to_dec(), -243);
+    assert_eq!(empty.to_bytes().len(), 2);
+}
+
+#[cfg(test)]
+#[test]
+fn pack_unpack_slice() {
+    let values = [0, 1, -1, 42, -364, 121, 122, i64::MAX, i64::MIN];
+    let packed = DataTernary::pack_slice(&values);
+    assert_eq!(packed.chunks.len(), values.len() * 9);
+    assert_eq!(packed.unpack_slice(), values.to_vec());
+
+    // Each record holds the value in balanced ternary.
+    let packed = DataTernary::pack_slice(&[42]);
+    assert_eq!(packed.to_dec(), 42);
+
+    assert_eq!(DataTernary::pack_slice(&[]).unpack_slice(), Vec::<i64>::new());
+
+    let column = DataTernary::from_dec_slice(&values[..7]);
+    assert_eq!(column.len(), 7);
+    for (data, value) in column.iter().zip(values.iter()) {
+        assert_eq!(data.to_dec(), *value);
+    }
+}
+
+#[cfg(test)]
+#[test]
+#[should_panic(expected = "DataTernary::unpack_slice(): Record out of range")]
+fn unpack_slice_out_of_range() {
+    DataTernary::from_bytes(&[121; 9]).unwrap().unpack_slice();
+}
+
+#[cfg(test)]
+#[test]
+fn neg_in_place() {
+    for value in [0, 1, -1, 42, -364, 121, 122, 1_000_000_007, -9_999_999_999] {
+        let mut data = DataTernary::from_dec(value);
+        data.neg_in_place();
+        let round_trip = DataTernary::from_ternary(-&DataTernary::from_dec(value).to_ternary());
+        assert_eq!(data.to_dec(), -value);
+        assert_eq!(data.to_ternary(), round_trip.to_ternary());
+    }
+
+    let mut packed = DataTernary::pack_slice(&[5, -7, i64::MAX]);
+    packed.neg_in_place();
+    assert_eq!(packed.unpack_slice(), [-5, 7, -i64::MAX].to_vec());
+}
+
+#[cfg(test)]
+#[test]
+fn from_bytes() {
+    let data = DataTernary::from_bytes(&[1, 0, 121, -121i8 as u8]).unwrap();
+    assert_eq!(data.to_dec(), 243 * 243 * 243 + 121 * 243 - 121);
+    assert_eq!(data.to_bytes(), [1, 0, 121, 135].to_vec());
+    for value in [0, 42, -1000, 1_000_000_007, i64::MIN / 3] {
+        let data = DataTernary::from_dec(value);
+        assert_eq!(DataTernary::from_bytes(&data.to_bytes()), Ok(data));
+    }
+
+    for repr in ["+", "+-0+-", "-0+0-+", "+-0+-0+-0+-0+-0+-0+-0+"] {
+        let data = DataTernary::from_ternary(Ternary::parse(repr));
+        let bytes = data.to_bytes();
+        assert_eq!(bytes.len(), repr.len().div_ceil(5));
+        assert_eq!(DataTernary::from_bytes(&bytes), Ok(data));
+    }
+    assert_eq!(DataTernary::from_bytes(&[]), Ok(DataTernary::default()));
+    assert_eq!(DataTernary::from_bytes(&[121, 135]).unwrap().to_bytes(), [121, 135].to_vec());
+    assert_eq!(DataTernary::from_bytes(&[-122i8 as u8]), Err(StoreError::OutOfRange));
+    assert_eq!(DataTernary::from_bytes(&[0, 122]), Err(StoreError::OutOfRange));
+    assert_eq!(DataTernary::from_bytes(&[0, 255, 128]), Err(StoreError::OutOfRange));
+}
+
+#[cfg(test)]
+#[test]
+fn view_bytes() {
+    let data = DataTernary::view_bytes(&[1, 0, 121, -121i8 as u8]).unwrap();
+    assert_eq!(data.to_dec(), 243 * 243 * 243 + 121 * 243 - 121);
+    assert_eq!(DataTernary::view_bytes(&[]), Ok(DataTernary::default()));
+    for value in [0, 42, -1000, 1_000_000_007, i64::MIN / 3] {
+        let data = DataTernary::from_dec(value);
+        assert_eq!(DataTernary::view_bytes(&data.to_bytes()), Ok(data));
+    }
+
+    assert_eq!(DataTernary::view_bytes(&[-122i8 as u8]), Err(RangeError));
+    assert_eq!(DataTernary::view_bytes(&[0, 122]), Err(RangeError));
+    assert_eq!(DataTernary::view_bytes(&[0, 255, 128]), Err(RangeError));
+}
+
+#[cfg(test)]
+#[test]
+fn store_errors() {
+    use crate::Ternary;
+
+    assert_eq!(TritsChunk::try_from_dec(121), Ok(TritsChunk(121)));
+    assert_eq!(TritsChunk::try_from_dec(-121), Ok(TritsChunk(-121)));
+    assert_eq!(TritsChunk::try_from_dec(122), Err(StoreError::OutOfRange));
+    assert_eq!(TritsChunk::try_from_dec(i8::MIN), Err(StoreError::OutOfRange));
+
+    let chunk = TritsChunk::try_from_ternary(&Ternary::parse("-----"));
+    assert_eq!(chunk, Ok(TritsChunk(-121)));
+    let too_long = Ternary::parse("+-0+-0");
+    assert_eq!(TritsChunk::try_from_ternary(&too_long), Err(StoreError::TooLong));
+    // Leading zeros are not significant.
+    let padded = Ternary::parse("00000+");
+    assert_eq!(TritsChunk::try_from_ternary(&padded), Ok(TritsChunk(1)));
+    let padded = Ternary::parse(&("0".repeat(50) + "+++++"));
+    assert_eq!(TritsChunk::try_from_ternary(&padded), Ok(TritsChunk(121)));
+    assert_eq!(TritsChunk::try_from_ternary(&Ternary::parse("")), Ok(TritsChunk(0)));
+
+    let max = Ternary::parse(&"+".repeat(40)).to_dec();
+    assert_eq!(Ter40::try_from_dec(max), Ok(Ter40(max)));
+    assert_eq!(Ter40::try_from_dec(-max), Ok(Ter40(-max)));
+    assert_eq!(Ter40::try_from_dec(max + 1), Err(StoreError::OutOfRange));
+    assert_eq!(Ter40::try_from_dec(i64::MIN), Err(StoreError::OutOfRange));
+}
+
+#[cfg(test)]
+#[test]
+fn to_ternary_padded() {
+    use crate::Ternary;
+
+    for value in [0, 1, -7, 42, 121, 122, -1_000_000_007] {
+        let data = DataTernary::from_dec(value);
+        let padded = data.to_ternary_padded();
+        assert_eq!(padded.log(), 5 * data.chunks.len());
+        assert_eq!(padded.trim(), data.to_ternary());
+        assert_eq!(padded.to_dec(), value);
+    }
+    let data = DataTernary::from_ternary(Ternary::parse("0000000+"));
+    assert_eq!(data.to_ternary_padded().to_string(), "000000000+");
+    assert_eq!(DataTernary::default().to_ternary_padded().log(), 0);
+}
+
+#[cfg(test)]
+#[test]
+fn checked_to_dec() {
+    for value in [i64::MIN, -1_000_000, -1, 0, 42, i64::MAX] {
+        assert_eq!(DataTernary::from_dec(value).checked_to_dec(), Some(value));
+    }
+    let max = Ternary::parse(&"+".repeat(40));
+    assert_eq!(DataTernary::from_ternary(max.clone()).checked_to_dec(), Some(max.to_dec()));
+
+    // 45 digits: out of range, with or without leading zero chunks.
+    let long = Ternary::parse(&"-".repeat(45));
+    assert_eq!(DataTernary::from_ternary(long.clone()).checked_to_dec(), None);
+    assert_eq!(DataTernary::from_ternary(long.with_length(60)).checked_to_dec(), None);
+    let padded = Ternary::parse("+-0").with_length(60);
+    assert_eq!(DataTernary::from_ternary(padded).checked_to_dec(), Some(6));
+}
+
+#[cfg(test)]
+#[test]
+fn ter80_range() {
+    use crate::Ternary;
+    use alloc::string::String;
+
+    let max = Ternary::parse(&"+".repeat(80));
+    let min = Ternary::parse(&"-".repeat(80));
+    assert_eq!(Ter80::try_from_ternary(&max), Ok(Ter80::MAX));
+    assert_eq!(Ter80::try_from_ternary(&min), Ok(Ter80::MIN));
+    assert_eq!(Ter80::MAX.to_ternary(), max);
+    assert_eq!(Ter80::MIN.to_ternary(), min);
+    assert_eq!(Ter80::MAX.to_dec(), (3_i128.pow(80) - 1) / 2);
+    assert_eq!(-Ter80::MAX, Ter80::MIN);
+
+    let too_long = Ternary::parse(&"+".repeat(81));
+    assert_eq!(Ter80::try_from_ternary(&too_long), Err(StoreError::TooLong));
+    let padded = Ternary::parse(&(String::from("000") + &"-".repeat(80)));
+    assert_eq!(Ter80::try_from_ternary(&padded), Ok(Ter80::MIN));
+
+    let max = Ter80::MAX.to_dec();
+    assert_eq!(Ter80::try_from_dec(max + 1), Err(StoreError::OutOfRange));
+    assert_eq!(Ter80::try_from_dec(-max - 1), Err(StoreError::OutOfRange));
+    assert_eq!(Ter80::try_from_dec(i128::MIN), Err(StoreError::OutOfRange));
+
+    for value in [0, 1, -1, 42, -1_000_000, i64::MAX as i128, i64::MIN as i128 * 3] {
+        let ter80 = Ter80::from_dec(value);
+        assert_eq!(ter80.to_ternary().log(), 80);
+        assert_eq!(Ter80::from_ternary(ter80.to_ternary()), ter80);
+    }
+    assert_eq!(Ter80::from_dec(-42).to_ternary().trim(), Ternary::from_dec(-42));
+    assert_eq!(Ter80::from(Ter40::from_dec(-42)), Ter80::from_dec(-42));
+}
+
+#[cfg(test)]
+#[test]
+fn ter80_operations() {
+    let a = Ter80::from_dec(i64::MAX as i128);
+    let b = Ter80::from_dec(1_000);
+    assert_eq!((a * b).to_dec(), i64::MAX as i128 * 1_000);
+    assert_eq!((a + b - b), a);
+    assert_eq!(((a * b) / b), a);
+    assert_eq!(Ter80::MAX & Ter80::MIN, Ter80::MIN);
+    assert_eq!(Ter80::MAX | Ter80::MIN, Ter80::MAX);
+    assert_eq!(b.each(Digit::post).each(Digit::pre), b);
+    assert_eq!(b.digit(0), Some(Digit::Pos));
+    assert_eq!(b.to_digits().len(), 80);
+}
+
+#[cfg(test)]
+#[test]
+#[should_panic(expected = "Ter80::from_dec(): Invalid value")]
+fn ter80_add_overflow() {
+    let _ = Ter80::MAX + Ter80::from_dec(1);
+}
+
+#[cfg(test)]
+#[test]
+#[should_panic(expected = "Overflow in multiplication.")]
+fn ter80_mul_overflow() {
+    let _ = Ter80::MAX * Ter80::MAX;
+}
+
+#[cfg(test)]
+#[test]
+fn add_assign_dec() {
+    let mut counter = DataTernary::from_dec(119);
+    assert_eq!(counter.chunks.len(), 1);
+    for expected in 120..=125 {
+        counter.add_assign_dec(1);
+        assert_eq!(counter.to_dec(), expected);
+    }
+    // 122 does not fit into one chunk anymore.
+    assert_eq!(counter.chunks.len(), 2);
+    counter.add_assign_dec(-250);
+    assert_eq!(counter.to_dec(), -125);
+    assert_eq!(counter.chunks.len(), 2);
+    counter.add_assign_dec(0);
+    assert_eq!(counter.to_dec(), -125);
+
+    let mut counter = DataTernary::from_dec(0);
+    counter.add_assign_dec(i64::MIN);
+    assert_eq!(counter.checked_to_dec(), Some(i64::MIN));
+    counter.add_assign_dec(i64::MAX);
+    assert_eq!(counter.to_dec(), -1);
+    for value in [-100_000, -243, -122, 121, 122, 59_048, 59_049] {
+        let mut counter = DataTernary::from_dec(121);
+        counter.add_assign_dec(value);
+        assert_eq!(counter.to_dec(), 121 + value);
+        assert_eq!(counter, DataTernary::from_dec(121 + value));
+    }
+
+    // Decrementing across a chunk boundary drops the leading zero chunk.
+    let mut counter = DataTernary::from_dec(122);
+    counter.add_assign_dec(-1);
+    assert_eq!(counter, DataTernary::from_dec(121));
+    assert_eq!(counter.chunks.len(), 1);
+    counter.add_assign_dec(-121);
+    assert_eq!(counter, DataTernary::from_dec(0));
+    let mut counter = DataTernary::from_dec(-59_049);
+    counter.add_assign_dec(59_049 - 5);
+    assert_eq!(counter, DataTernary::from_dec(-5));
+}
@@ -5,11 +5,61 @@ use crate::{
 };
 use alloc::string::{String, ToString};
 use alloc::vec::Vec;
+use core::cmp::Ordering;
 use core::fmt::{Display, Formatter};
-use core::ops::{Add, BitAnd, BitOr, BitXor, Div, Mul, Neg as StdNeg, Not, Sub};
+use core::ops::{Add, BitAnd, BitOr, BitXor, Div, Mul, Neg as StdNeg, Not, Shl, Shr, Sub};
 use core::str::FromStr;
 use crate::concepts::DigitOperate;
 
+/// Error returned when a sequence of digits is too long to fit into a [Tryte].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TryteLengthError;
+
+impl Display for TryteLengthError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "too many digits for the size of the Tryte")
+    }
+}
+
+impl core::error::Error for TryteLengthError {}
+
+/// Error returned when converting a [Ternary] with too many digits into a [Tryte].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TryteFromTernaryError {
+    /// The number of digits of the `Ternary`.
+    pub length: usize,
+    /// The size of the `Tryte`.
+    pub size: usize,
+}
+
+impl Display for TryteFromTernaryError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "cannot fit {} digits into a Tryte<{}>", self.length, self.size)
+    }
+}
+
+impl core::error::Error for TryteFromTernaryError {}
+
+/// Error returned by [Tryte::try_from_str].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TryteError {
+    /// The string contains a character which is not `+`, `0` or `-`.
+    InvalidChar,
+    /// The string has more digits than the size of the `Tryte`.
+    TooLong,
+}
+
+impl Display for TryteError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            TryteError::InvalidChar => write!(f, "invalid character in balanced ternary string"),
+            TryteError::TooLong => write!(f, "too many digits for the size of the Tryte"),
+        }
+    }
+}
+
+impl core::error::Error for TryteError {}
+
 /// The `Tryte<S>` struct represents a Copy type balanced ternary number with exactly S digits (6 by default).
 /// Each digit in a balanced ternary system can have one of three values: -1, 0, or 1.
 ///
@@ -78,6 +128,56 @@ impl<const SIZE: usize> Tryte<SIZE> {
         Self { raw: digits }
     }
 
+    /// Creates a `Tryte` from a slice of `Digit`s of at most SIZE digits.
+    ///
+    /// The digits are right-aligned: a shorter slice is padded with `Zero` on the left.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [TryteLengthError] if the slice contains more than SIZE digits.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use balanced_ternary::{Tryte, Digit::{Pos, Zero, Neg}};
+    ///
+    /// let tryte = Tryte::<6>::from_digits(&[Pos, Zero, Neg, Pos]).unwrap();
+    /// assert_eq!(tryte.to_string(), "00+0-+");
+    /// assert!(Tryte::<3>::from_digits(&[Pos, Zero, Neg, Pos]).is_err());
+    /// ```
+    pub fn from_digits(v: &[Digit]) -> Result<Self, TryteLengthError> {
+        if v.len() > SIZE {
+            return Err(TryteLengthError);
+        }
+        let mut digits = [Zero; SIZE];
+        digits[SIZE - v.len()..].copy_from_slice(v);
+        Ok(Self::new(digits))
+    }
+
+    /// Parses a balanced ternary string of at most SIZE digits into a `Tryte`.
+    ///
+    /// Unlike `From<&str>`, this never panics. As with [Tryte::from_digits], a shorter string is
+    /// padded with `Zero` on the left, and leading `0`s count as digits.
+    ///
+    /// # Errors
+    ///
+    /// - [TryteError::InvalidChar] if a character is not `+`, `0` or `-`,
+    /// - [TryteError::TooLong] if the string contains more than SIZE digits.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use balanced_ternary::{Tryte, TryteError};
+    ///
+    /// assert_eq!(Tryte::<6>::try_from_str("+0-").unwrap().to_string(), "000+0-");
+    /// assert_eq!(Tryte::<3>::try_from_str("+0-+"), Err(TryteError::TooLong));
+    /// assert_eq!(Tryte::<3>::try_from_str("+x-"), Err(TryteError::InvalidChar));
+    /// ```
+    pub fn try_from_str(s: &str) -> Result<Self, TryteError> {
+        let ternary = Ternary::from_str(s).map_err(|_| TryteError::InvalidChar)?;
+        Self::from_digits(ternary.to_digit_slice()).map_err(|_| TryteError::TooLong)
+    }
+
     /// Converts the `Tryte` into its `Ternary` representation.
     ///
     /// # Returns
@@ -99,6 +199,30 @@ impl<const SIZE: usize> Tryte<SIZE> {
         &self.raw
     }
 
+    /// Returns a reference to the fixed-size array of the digits of the `Tryte`.
+    ///
+    /// The digits are ordered from the most significant to the least significant.
+    pub const fn as_array(&self) -> &[Digit; SIZE] {
+        &self.raw
+    }
+
+    /// Returns a mutable reference to the fixed-size array of the digits of the `Tryte`.
+    ///
+    /// The digits are ordered from the most significant to the least significant.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use balanced_ternary::{Tryte, Digit::Pos};
+    ///
+    /// let mut tryte = Tryte::<6>::ZERO;
+    /// tryte.as_mut_array()[4] = Pos;
+    /// assert_eq!(tryte.to_i64(), 3);
+    /// ```
+    pub fn as_mut_array(&mut self) -> &mut [Digit; SIZE] {
+        &mut self.raw
+    }
+
     /// Creates a `Tryte` from the given `Ternary`.
     ///
     /// # Arguments
@@ -122,13 +246,87 @@ impl<const SIZE: usize> Tryte<SIZE> {
         Self::new(digits)
     }
 
+    /// Creates a `Tryte` from the given `Ternary`, clamping to [Tryte::MAX] or [Tryte::MIN]
+    /// when the value is out of range.
+    ///
+    /// Leading `Zero` digits are ignored. Unlike [Tryte::from_ternary], this never panics and,
+    /// unlike [Ternary::to_fixed_width_wrapping], this never wraps around.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use balanced_ternary::{Ternary, Tryte};
+    ///
+    /// assert_eq!(Tryte::<6>::from_ternary_saturating(&Ternary::from_dec(1000)), Tryte::MAX);
+    /// assert_eq!(Tryte::<6>::from_ternary_saturating(&Ternary::from_dec(-1000)), Tryte::MIN);
+    /// assert_eq!(Tryte::<6>::from_ternary_saturating(&Ternary::from_dec(42)).to_i64(), 42);
+    /// ```
+    pub fn from_ternary_saturating(v: &Ternary) -> Self {
+        let digits = v.to_digit_slice();
+        let leading = digits.iter().take_while(|d| **d == Zero).count();
+        match Self::from_digits(&digits[leading..]) {
+            Ok(tryte) => tryte,
+            Err(_) if digits[leading] == Pos => Self::MAX,
+            Err(_) => Self::MIN,
+        }
+    }
+
     /// Converts the `Tryte` into a signed 64-bit integer.
     ///
     /// # Returns
     ///
     /// A `i64` representing the decimal value of the `Tryte`.
+    ///
+    /// Same as [Tryte::value].
     pub fn to_i64(&self) -> i64 {
-        self.to_ternary().to_dec()
+        self.value()
+    }
+
+    /// Converts the `Tryte` into a signed 64-bit integer, checking for overflows.
+    ///
+    /// As a `Tryte` cannot have more than 40 digits, its value always fits into an `i64`
+    /// and this never returns `None`: it exists for consistency with
+    /// [DataTernary::checked_to_dec](crate::DataTernary::checked_to_dec) and [Ternary::eval_at].
+    ///
+    /// # Returns
+    ///
+    /// * `Option<i64>` - The decimal value, or `None` if it does not fit into an `i64`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use balanced_ternary::Tryte;
+    ///
+    /// assert_eq!(Tryte::<40>::MAX.checked_to_dec(), Some(6_078_832_729_528_464_400));
+    /// ```
+    pub fn checked_to_dec(&self) -> Option<i64> {
+        self.raw.iter().try_fold(0i64, |value, digit| {
+            value.checked_mul(3)?.checked_add(digit.to_i8() as i64)
+        })
+    }
+
+    /// Returns the decimal value of the `Tryte`.
+    ///
+    /// The value is evaluated directly from the fixed array of digits: unlike a conversion
+    /// through [Tryte::to_ternary], no allocation occurs.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use balanced_ternary::Tryte;
+    ///
+    /// const VALUE: i64 = Tryte::<6>::MAX.value();
+    /// assert_eq!(VALUE, 364);
+    /// assert_eq!(Tryte::<6>::from("+-0").value(), 6);
+    /// ```
+    pub const fn value(&self) -> i64 {
+        let mut value = 0;
+        let mut i = 0;
+        while i < SIZE {
+            value = value * 3 + self.raw[i].to_i8() as i64;
+            i += 1;
+        }
+        value
     }
 
     /// Creates a `Tryte` from a signed 64-bit integer.
@@ -144,6 +342,337 @@ impl<const SIZE: usize> Tryte<SIZE> {
         Self::from_ternary(&Ternary::from_dec(v))
     }
 
+    /// Creates a `Tryte` from a signed 64-bit integer, or `None` if it does not fit into SIZE digits.
+    fn checked_from_i64(v: i64) -> Option<Self> {
+        if v.unsigned_abs() > Self::MAX.to_i64().unsigned_abs() {
+            return None;
+        }
+        Some(Self::from_i64(v))
+    }
+
+    /// Adds two `Tryte`s, returning `None` if the result does not fit into SIZE digits.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use balanced_ternary::Tryte;
+    ///
+    /// let one = Tryte::<3>::from_i64(1);
+    /// assert_eq!(Tryte::<3>::from_i64(12).checked_add(one), Some(Tryte::<3>::MAX));
+    /// assert_eq!(Tryte::<3>::MAX.checked_add(one), None);
+    /// ```
+    pub fn checked_add(self, rhs: Self) -> Option<Self> {
+        Self::checked_from_i64(self.to_i64().checked_add(rhs.to_i64())?)
+    }
+
+    /// Subtracts two `Tryte`s, returning `None` if the result does not fit into SIZE digits.
+    pub fn checked_sub(self, rhs: Self) -> Option<Self> {
+        Self::checked_from_i64(self.to_i64().checked_sub(rhs.to_i64())?)
+    }
+
+    /// Multiplies two `Tryte`s, returning `None` if the result does not fit into SIZE digits.
+    pub fn checked_mul(self, rhs: Self) -> Option<Self> {
+        Self::checked_from_i64(self.to_i64().checked_mul(rhs.to_i64())?)
+    }
+
+    /// Divides two `Tryte`s, returning `None` if `rhs` is zero.
+    pub fn checked_div(self, rhs: Self) -> Option<Self> {
+        Self::checked_from_i64(self.to_i64().checked_div(rhs.to_i64())?)
+    }
+
+    /// Reduces a `Ternary` modulo `3^SIZE` into a `Tryte`, telling whether digits were lost.
+    fn wrap_ternary(ternary: &Ternary) -> (Self, bool) {
+        let digits = ternary.to_digit_slice();
+        let lost = &digits[..digits.len().saturating_sub(SIZE)];
+        let overflow = lost.iter().any(|d| *d != Zero);
+        (Self::from_ternary(&ternary.to_fixed_width_wrapping(SIZE)), overflow)
+    }
+
+    /// Adds two `Tryte`s, wrapping around modulo `3^SIZE` like a fixed-width register.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use balanced_ternary::Tryte;
+    ///
+    /// let one = Tryte::<3>::from_i64(1);
+    /// assert_eq!(Tryte::<3>::MAX.wrapping_add(one), Tryte::<3>::MIN);
+    /// ```
+    pub fn wrapping_add(self, rhs: Self) -> Self {
+        self.overflowing_add(rhs).0
+    }
+
+    /// Subtracts two `Tryte`s, wrapping around modulo `3^SIZE` like a fixed-width register.
+    pub fn wrapping_sub(self, rhs: Self) -> Self {
+        self.overflowing_sub(rhs).0
+    }
+
+    /// Multiplies two `Tryte`s, wrapping around modulo `3^SIZE` like a fixed-width register.
+    pub fn wrapping_mul(self, rhs: Self) -> Self {
+        self.overflowing_mul(rhs).0
+    }
+
+    /// Adds two `Tryte`s, wrapping around modulo `3^SIZE`, and tells whether the exact sum
+    /// did not fit into SIZE digits.
+    ///
+    /// The addition is performed digit by digit, so it does not overflow, whatever `SIZE`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use balanced_ternary::Tryte;
+    ///
+    /// let one = Tryte::<3>::from_i64(1);
+    /// assert_eq!(Tryte::<3>::MAX.overflowing_add(one), (Tryte::<3>::MIN, true));
+    /// assert_eq!(one.overflowing_add(one), (Tryte::<3>::from_i64(2), false));
+    /// ```
+    pub fn overflowing_add(self, rhs: Self) -> (Self, bool) {
+        Self::wrap_ternary(&self.to_ternary().add_digits(&rhs.to_ternary()))
+    }
+
+    /// Subtracts two `Tryte`s, wrapping around modulo `3^SIZE`, and tells whether the exact
+    /// difference did not fit into SIZE digits.
+    pub fn overflowing_sub(self, rhs: Self) -> (Self, bool) {
+        Self::wrap_ternary(&self.to_ternary().add_digits(&-&rhs.to_ternary()))
+    }
+
+    /// Multiplies two `Tryte`s, wrapping around modulo `3^SIZE`, and tells whether the exact
+    /// product did not fit into SIZE digits.
+    pub fn overflowing_mul(self, rhs: Self) -> (Self, bool) {
+        Self::wrap_ternary(&self.to_ternary().mul_digits(&rhs.to_ternary()))
+    }
+
+    /// Multiplies two `Tryte`s into a `Tryte<N>` wide enough to hold the exact product.
+    ///
+    /// Unlike `Mul`, no digit is lost: the product of two `SIZE` digits numbers always fits
+    /// into `2 * SIZE` digits. The multiplication is performed digit by digit, so it does not
+    /// overflow, whatever `SIZE`.
+    ///
+    /// Using an `N` smaller than `2 * SIZE` is a compile-time error.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use balanced_ternary::Tryte;
+    ///
+    /// let max = Tryte::<3>::MAX;
+    /// let product: Tryte<6> = max.widening_mul(max);
+    /// assert_eq!(product.to_i64(), 169);
+    /// ```
+    pub fn widening_mul<const N: usize>(self, rhs: Self) -> Tryte<N> {
+        const { assert!(N >= 2 * SIZE, "The result must have at least 2 * SIZE digits.") };
+        Tryte::from_ternary(&self.to_ternary().mul_digits(&rhs.to_ternary()))
+    }
+
+    /// Returns `true` if every `Tryte<SIZE>` fits into a `Tryte<N>`, that is if `SIZE <= N`.
+    ///
+    /// When it returns `true`, [Tryte::resize] into a `Tryte<N>` never panics.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use balanced_ternary::Tryte;
+    ///
+    /// assert!(Tryte::<3>::fits_in::<6>());
+    /// assert!(!Tryte::<6>::fits_in::<3>());
+    /// ```
+    pub const fn fits_in<const N: usize>() -> bool {
+        SIZE <= N
+    }
+
+    /// Iterates over all the `3^SIZE` values of a `Tryte<SIZE>`, from [Tryte::MIN] to
+    /// [Tryte::MAX] in numeric order. Useful for exhaustive tests.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use balanced_ternary::Tryte;
+    ///
+    /// assert_eq!(Tryte::<3>::all().count(), 27);
+    /// let values = Tryte::<2>::all().map(|t| t.to_i64()).collect::<Vec<_>>();
+    /// assert_eq!(values, (-4..=4).collect::<Vec<_>>());
+    /// ```
+    pub fn all() -> impl Iterator<Item = Tryte<SIZE>> {
+        core::iter::successors(Some(Self::MIN), |previous| {
+            let mut next = *previous;
+            for digit in next.raw.iter_mut().rev() {
+                if *digit == Pos {
+                    *digit = Neg;
+                } else {
+                    *digit = digit.post();
+                    return Some(next);
+                }
+            }
+            None
+        })
+    }
+
+    /// Converts the `Tryte` into a `Tryte<N>` of the same value.
+    ///
+    /// Widening pads the digits with `Zero`, narrowing drops leading `Zero` digits.
+    /// A generic `From<Tryte<A>> for Tryte<B>` would conflict with `From<T> for T`,
+    /// hence this method.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value has more than `N` significant digits, which can only happen
+    /// when narrowing (see [Tryte::fits_in]).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use balanced_ternary::Tryte;
+    ///
+    /// let wide: Tryte<6> = Tryte::<3>::from("+0-").resize();
+    /// assert_eq!(wide.to_string(), "000+0-");
+    /// let narrow: Tryte<3> = wide.resize();
+    /// assert_eq!(narrow.to_string(), "+0-");
+    /// ```
+    ///
+    /// ```should_panic
+    /// use balanced_ternary::Tryte;
+    ///
+    /// let _: Tryte<3> = Tryte::<6>::from("+0000-").resize();
+    /// ```
+    pub fn resize<const N: usize>(self) -> Tryte<N> {
+        let first = self.raw.iter().position(|d| *d != Zero).unwrap_or(SIZE);
+        match Tryte::from_digits(&self.raw[first..]) {
+            Ok(tryte) => tryte,
+            Err(_) => panic!(
+                "Cannot convert a Tryte<{}> with more than {} significant digits to a Tryte<{}>.",
+                SIZE, N, N
+            ),
+        }
+    }
+
+    /// Cyclically rotates the digits of the `Tryte` to the left (towards the most significant digit).
+    ///
+    /// Digits leaving the most significant end re-enter at the least significant end.
+    /// The rotation amount is taken modulo `SIZE`.
+    ///
+    /// # Arguments
+    ///
+    /// * `n` - The number of positions to rotate.
+    ///
+    /// # Returns
+    ///
+    /// A new `Tryte` with its digits rotated.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use balanced_ternary::Tryte;
+    ///
+    /// let tryte = Tryte::<6>::from("+0-00+");
+    /// assert_eq!(tryte.rotate_left(1).to_string(), "0-00++");
+    /// assert_eq!(tryte.rotate_left(7), tryte.rotate_left(1));
+    /// ```
+    pub fn rotate_left(self, n: usize) -> Self {
+        let mut raw = self.raw;
+        if SIZE > 0 {
+            raw.rotate_left(n % SIZE);
+        }
+        Self { raw }
+    }
+
+    /// Cyclically rotates the digits of the `Tryte` to the right (towards the least significant digit).
+    ///
+    /// Digits leaving the least significant end re-enter at the most significant end.
+    /// The rotation amount is taken modulo `SIZE`.
+    ///
+    /// # Arguments
+    ///
+    /// * `n` - The number of positions to rotate.
+    ///
+    /// # Returns
+    ///
+    /// A new `Tryte` with its digits rotated.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use balanced_ternary::Tryte;
+    ///
+    /// let tryte = Tryte::<6>::from("+0-00+");
+    /// assert_eq!(tryte.rotate_right(1).to_string(), "++0-00");
+    /// assert_eq!(tryte.rotate_right(1).rotate_left(1), tryte);
+    /// ```
+    pub fn rotate_right(self, n: usize) -> Self {
+        let mut raw = self.raw;
+        if SIZE > 0 {
+            raw.rotate_right(n % SIZE);
+        }
+        Self { raw }
+    }
+
+    /// Reverses the order of the digits of the `Tryte` within its fixed width.
+    ///
+    /// This is an operation on the representation (to follow another trit order convention),
+    /// not an arithmetic one: the value of the result is generally different.
+    ///
+    /// # Returns
+    ///
+    /// A new `Tryte` whose most significant digit is the least significant digit of `self`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use balanced_ternary::Tryte;
+    ///
+    /// let tryte = Tryte::<6>::from("+0-00+");
+    /// assert_eq!(tryte.reverse_digits().to_string(), "+00-0+");
+    /// assert_eq!(tryte.reverse_digits().reverse_digits(), tryte);
+    /// ```
+    pub fn reverse_digits(self) -> Self {
+        let mut raw = self.raw;
+        raw.reverse();
+        Self { raw }
+    }
+
+    /// Returns the weight of the `Tryte`: the number of its non-`Zero` digits.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use balanced_ternary::Tryte;
+    ///
+    /// assert_eq!(Tryte::<6>::from("+0-0+0").weight(), 3);
+    /// assert_eq!(Tryte::<6>::ZERO.weight(), 0);
+    /// ```
+    pub fn weight(&self) -> usize {
+        self.raw.iter().filter(|d| **d != Zero).count()
+    }
+
+    /// Returns the balance of the `Tryte`: the number of `Pos` digits minus the number of `Neg` digits.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use balanced_ternary::Tryte;
+    ///
+    /// assert_eq!(Tryte::<6>::from("+0-0+0").balance(), 1);
+    /// assert_eq!(Tryte::<6>::MIN.balance(), -6);
+    /// ```
+    pub fn balance(&self) -> i32 {
+        self.raw.iter().map(|d| d.to_i8() as i32).sum()
+    }
+}
+
+impl Tryte<6> {
+    /// Multiplies two `Tryte<6>` into a `Tryte<12>` holding the exact product.
+    ///
+    /// Shorthand for [Tryte::widening_mul] with the default `SIZE`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use balanced_ternary::Tryte;
+    ///
+    /// assert_eq!(Tryte::MAX.mul_wide(Tryte::MAX).to_i64(), 364 * 364);
+    /// ```
+    pub fn mul_wide(self, rhs: Self) -> Tryte<12> {
+        self.widening_mul(rhs)
+    }
 }
 
 impl<const SIZE: usize> DigitOperate for Tryte<SIZE> {
@@ -199,8 +728,60 @@ impl<const SIZE: usize> Display for Tryte<SIZE> {
     ///
     /// The `Tryte` is displayed in its balanced ternary representation
     /// as a SIZE-character string.
+    ///
+    /// The fill, alignment and width of the formatter are respected. With the alternate flag
+    /// (`{:#}`), the `Tryte` is rather padded on the left with `Zero` trits up to the width,
+    /// which keeps the value unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use balanced_ternary::Tryte;
+    ///
+    /// let tryte = Tryte::<6>::from("+0+0");
+    /// assert_eq!(format!("{}", tryte), "00+0+0");
+    /// assert_eq!(format!("{:>8}", tryte), "  00+0+0");
+    /// assert_eq!(format!("{:_<8}", tryte), "00+0+0__");
+    /// assert_eq!(format!("{:#8}", tryte), "0000+0+0");
+    /// ```
     fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
-        write!(f, "{:01$}", self.to_ternary().to_string(), SIZE)
+        let repr = self.to_ternary().to_string();
+        if f.alternate() {
+            let padding = f.width().unwrap_or(0).saturating_sub(SIZE);
+            write!(f, "{}{}", "0".repeat(padding), repr)
+        } else {
+            f.pad(&repr)
+        }
+    }
+}
+
+/// Compares the values of two `Tryte`s.
+///
+/// As both have `SIZE` digits, the first differing digit from the most significant one
+/// decides, without converting to `i64`.
+///
+/// # Example
+///
+/// ```
+/// use balanced_ternary::Tryte;
+///
+/// assert!(Tryte::<6>::from_i64(-3) < Tryte::<6>::from_i64(2));
+/// assert!(Tryte::<6>::MAX > Tryte::<6>::ZERO);
+/// ```
+impl<const SIZE: usize> Ord for Tryte<SIZE> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.raw
+            .iter()
+            .zip(other.raw.iter())
+            .map(|(a, b)| a.to_i8().cmp(&b.to_i8()))
+            .find(|ordering| *ordering != Ordering::Equal)
+            .unwrap_or(Ordering::Equal)
+    }
+}
+
+impl<const SIZE: usize> PartialOrd for Tryte<SIZE> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
     }
 }
 
@@ -211,6 +792,10 @@ impl<const SIZE: usize> StdNeg for Tryte<SIZE> {
     }
 }
 
+/// # Panics
+///
+/// Panics if the sum does not fit into SIZE digits. Use [Tryte::checked_add],
+/// [Tryte::wrapping_add] or [Tryte::overflowing_add] to handle the overflow.
 impl<const SIZE: usize> Add for Tryte<SIZE> {
     type Output = Tryte<SIZE>;
 
@@ -219,6 +804,10 @@ impl<const SIZE: usize> Add for Tryte<SIZE> {
     }
 }
 
+/// # Panics
+///
+/// Panics if the difference does not fit into SIZE digits. Use [Tryte::checked_sub],
+/// [Tryte::wrapping_sub] or [Tryte::overflowing_sub] to handle the overflow.
 impl<const SIZE: usize> Sub for Tryte<SIZE> {
     type Output = Tryte<SIZE>;
 
@@ -227,6 +816,10 @@ impl<const SIZE: usize> Sub for Tryte<SIZE> {
     }
 }
 
+/// # Panics
+///
+/// Panics if the product does not fit into SIZE digits. Use [Tryte::checked_mul],
+/// [Tryte::wrapping_mul] or [Tryte::overflowing_mul] to handle the overflow.
 impl<const SIZE: usize> Mul for Tryte<SIZE> {
     type Output = Tryte<SIZE>;
 
@@ -271,6 +864,57 @@ impl<const SIZE: usize> Not for Tryte<SIZE> {
     }
 }
 
+/// Shifts the digits of the `Tryte` to the left within its fixed width, like a hardware register.
+///
+/// Vacated positions are filled with `Zero` and the digits shifted past the most significant
+/// end are dropped. Unlike a multiplication by `3^rhs`, this never overflows: shifting by
+/// `SIZE` or more gives [Tryte::ZERO].
+///
+/// # Example
+///
+/// ```
+/// use balanced_ternary::Tryte;
+///
+/// let tryte = Tryte::<6>::from("+0-00+");
+/// assert_eq!((tryte << 2).to_string(), "-00+00");
+/// assert_eq!(tryte << 6, Tryte::ZERO);
+/// ```
+impl<const SIZE: usize> Shl<usize> for Tryte<SIZE> {
+    type Output = Tryte<SIZE>;
+    fn shl(self, rhs: usize) -> Self::Output {
+        let mut raw = [Zero; SIZE];
+        if rhs < SIZE {
+            raw[..SIZE - rhs].copy_from_slice(&self.raw[rhs..]);
+        }
+        Self { raw }
+    }
+}
+
+/// Shifts the digits of the `Tryte` to the right within its fixed width, like a hardware register.
+///
+/// Vacated positions are filled with `Zero` and the least significant digits are dropped,
+/// which divides the value by `3^rhs`, rounding to the nearest integer.
+///
+/// # Example
+///
+/// ```
+/// use balanced_ternary::Tryte;
+///
+/// let tryte = Tryte::<6>::from("+0-00+");
+/// assert_eq!((tryte >> 2).to_string(), "00+0-0");
+/// assert_eq!(tryte >> 6, Tryte::ZERO);
+/// ```
+impl<const SIZE: usize> Shr<usize> for Tryte<SIZE> {
+    type Output = Tryte<SIZE>;
+    fn shr(self, rhs: usize) -> Self::Output {
+        let mut raw = [Zero; SIZE];
+        if rhs < SIZE {
+            raw[rhs..].copy_from_slice(&self.raw[..SIZE - rhs]);
+        }
+        Self { raw }
+    }
+}
+
 impl<const SIZE: usize> From<Ternary> for Tryte<SIZE> {
     fn from(value: Ternary) -> Self {
         Tryte::from_ternary(&value)
@@ -283,6 +927,33 @@ impl<const SIZE: usize> From<Tryte<SIZE>> for Ternary {
     }
 }
 
+/// Converts a `Ternary` into a `Tryte` without panicking, unlike [Tryte::from_ternary].
+///
+/// It is implemented for `&Ternary` because `From<Ternary>` already provides a (panicking)
+/// `TryFrom<Ternary>`. As with `from_ternary`, leading `Zero` digits count.
+///
+/// # Example
+///
+/// ```
+/// use balanced_ternary::{ter, Tryte, TryteFromTernaryError};
+///
+/// assert_eq!(Tryte::<3>::try_from(&ter("+0-")), Ok(Tryte::from("+0-")));
+/// assert_eq!(
+///     Tryte::<3>::try_from(&ter("+0-+")),
+///     Err(TryteFromTernaryError { length: 4, size: 3 })
+/// );
+/// ```
+impl<const SIZE: usize> TryFrom<&Ternary> for Tryte<SIZE> {
+    type Error = TryteFromTernaryError;
+
+    fn try_from(value: &Ternary) -> Result<Self, Self::Error> {
+        Self::from_digits(value.to_digit_slice()).map_err(|_| TryteFromTernaryError {
+            length: value.log(),
+            size: SIZE,
+        })
+    }
+}
+
 impl<const SIZE: usize> From<&str> for Tryte<SIZE> {
     fn from(value: &str) -> Self {
         Self::from_ternary(&Ternary::parse(value))
@@ -309,7 +980,7 @@ impl<const SIZE: usize> From<i64> for Tryte<SIZE> {
 
 impl<const SIZE: usize> From<Tryte<SIZE>> for i64 {
     fn from(value: Tryte<SIZE>) -> Self {
-        value.to_i64()
+        value.value()
     }
 }
 
@@ -321,6 +992,38 @@ impl<const SIZE: usize> FromStr for Tryte<SIZE> {
     }
 }
 
+/// Serializes a [Tryte] as its fixed width string (e.g. `"000+-0"`).
+#[cfg(feature = "serde")]
+impl<const SIZE: usize> serde::Serialize for Tryte<SIZE> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+/// Deserializes a [Tryte] from a string of at most `SIZE` digits.
+///
+/// Shorter strings are padded with [Zero]s, longer ones are rejected.
+#[cfg(feature = "serde")]
+impl<'de, const SIZE: usize> serde::Deserialize<'de> for Tryte<SIZE> {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct TryteVisitor<const SIZE: usize>;
+
+        impl<const SIZE: usize> serde::de::Visitor<'_> for TryteVisitor<SIZE> {
+            type Value = Tryte<SIZE>;
+
+            fn expecting(&self, f: &mut Formatter) -> core::fmt::Result {
+                write!(f, "a balanced ternary string of at most {} digits", SIZE)
+            }
+
+            fn visit_str<E: serde::de::Error>(self, v: &str) -> Result<Self::Value, E> {
+                Tryte::try_from_str(v).map_err(E::custom)
+            }
+        }
+
+        deserializer.deserialize_str(TryteVisitor::<SIZE>)
+    }
+}
+
 #[cfg(test)]
 #[test]
 pub fn test_tryte() {
@@ -350,3 +1053,353 @@ pub fn test_tryte_from_str() {
 
     assert!(Tryte::<6>::from_str("+-x").is_err());
 }
+
+#[cfg(test)]
+#[test]
+pub fn test_tryte_rotate() {
+    let tryte = Tryte::<6>::from("+0-00+");
+
+    assert_eq!(tryte.rotate_left(2).to_string(), "-00++0");
+    assert_eq!(tryte.rotate_right(2).to_string(), "0++0-0");
+
+    // A full rotation gives back the original value.
+    assert_eq!(tryte.rotate_left(6), tryte);
+    assert_eq!(tryte.rotate_right(6), tryte);
+    assert_eq!(tryte.rotate_left(0), tryte);
+
+    // Rotating is cyclic and reversible.
+    assert_eq!(tryte.rotate_left(8), tryte.rotate_left(2));
+    assert_eq!(tryte.rotate_right(3).rotate_left(3), tryte);
+    assert_eq!(tryte.rotate_left(4), tryte.rotate_right(2));
+    for n in [1, 5, 7, 13, 100, usize::MAX] {
+        assert_eq!(tryte.rotate_left(n).rotate_right(n), tryte);
+        assert_eq!(tryte.rotate_left(n).weight(), tryte.weight());
+    }
+    assert_eq!(Tryte::<0>::ZERO.rotate_left(3), Tryte::<0>::ZERO);
+}
+
+#[cfg(test)]
+#[test]
+pub fn test_tryte_weight() {
+    let tryte = Tryte::<6>::from("+0-0+0");
+    assert_eq!(tryte.weight(), 3);
+    assert_eq!(tryte.balance(), 1);
+
+    assert_eq!(Tryte::<6>::MAX.weight(), 6);
+    assert_eq!(Tryte::<6>::MAX.balance(), 6);
+    assert_eq!(Tryte::<6>::ZERO.weight(), 0);
+    assert_eq!(Tryte::<6>::ZERO.balance(), 0);
+    assert_eq!(Tryte::<6>::from("--+-").weight(), 4);
+    assert_eq!(Tryte::<6>::from("--+-").balance(), -2);
+}
+
+#[cfg(test)]
+#[test]
+pub fn test_tryte_array() {
+    let mut tryte = Tryte::<6>::from_i64(16);
+    assert_eq!(tryte.as_array(), &[Zero, Zero, Pos, Neg, Neg, Pos]);
+
+    tryte.as_mut_array()[5] = Neg;
+    assert_eq!(tryte.to_i64(), 14);
+    tryte.as_mut_array()[0] = Pos;
+    assert_eq!(tryte.to_i64(), 14 + 243);
+    assert_eq!(tryte.to_string(), "+0+---");
+}
+
+#[cfg(test)]
+#[test]
+pub fn test_tryte_checked_ops() {
+    type T3 = Tryte<3>;
+    let max = T3::MAX;
+    let min = T3::MIN;
+    let two = T3::from_i64(2);
+
+    assert_eq!(max.checked_add(max), None);
+    assert_eq!(min.checked_add(min), None);
+    assert_eq!(max.checked_add(min), Some(T3::ZERO));
+    assert_eq!(min.checked_sub(two), None);
+    assert_eq!(max.checked_sub(two), Some(T3::from_i64(11)));
+    assert_eq!(max.checked_mul(two), None);
+    assert_eq!(T3::from_i64(6).checked_mul(two), Some(max - T3::from_i64(1)));
+    assert_eq!(max.checked_div(T3::ZERO), None);
+    assert_eq!(max.checked_div(two), Some(T3::from_i64(6)));
+    assert_eq!(min.checked_div(T3::from_i64(-1)), Some(max));
+
+    let max40 = Tryte::<40>::MAX;
+    assert_eq!(max40.checked_add(Tryte::<40>::from_i64(1)), None);
+    assert_eq!(max40.checked_add(Tryte::<40>::MIN), Some(Tryte::<40>::ZERO));
+    assert_eq!(max40.checked_mul(max40), None);
+
+    let one = Tryte::<6>::from_i64(1);
+    assert_eq!(Tryte::<6>::MAX.checked_add(one), None);
+    assert_eq!(Tryte::<6>::from_i64(363).checked_add(one), Some(Tryte::MAX));
+    assert_eq!(Tryte::<6>::MIN.checked_sub(one), None);
+    assert_eq!(Tryte::<6>::from_i64(0).checked_div(Tryte::ZERO), None);
+
+    // `None` exactly when the overflowing variants report an overflow.
+    let samples = [-364, -200, -1, 0, 1, 2, 121, 364].map(Tryte::<6>::from_i64);
+    for a in samples {
+        for b in samples {
+            assert_eq!(a.checked_add(b).is_none(), a.overflowing_add(b).1);
+            assert_eq!(a.checked_sub(b).is_none(), a.overflowing_sub(b).1);
+            assert_eq!(a.checked_mul(b).is_none(), a.overflowing_mul(b).1);
+        }
+    }
+}
+
+#[cfg(test)]
+#[test]
+pub fn test_tryte_from_digits() {
+    let tryte = Tryte::<6>::from_digits(&[Pos, Neg, Zero, Pos]).unwrap();
+    assert_eq!(tryte.to_string(), "00+-0+");
+    assert_eq!(tryte.to_i64(), 19);
+
+    let digits = [Pos, Neg, Zero, Pos, Neg, Neg];
+    assert_eq!(Tryte::<6>::from_digits(&digits), Ok(Tryte::new(digits)));
+    assert_eq!(Tryte::<6>::from_digits(&[]), Ok(Tryte::ZERO));
+    assert_eq!(
+        Tryte::<6>::from_digits(&[Pos, Neg, Zero, Pos, Neg, Neg, Zero]),
+        Err(TryteLengthError)
+    );
+}
+
+#[cfg(test)]
+#[test]
+pub fn test_tryte_widening_mul() {
+    let max = Tryte::<6>::MAX;
+    let min = Tryte::<6>::MIN;
+    assert_eq!(max.mul_wide(max).to_i64(), 132_496);
+    assert_eq!(max.mul_wide(min).to_i64(), -132_496);
+    assert_eq!(min.mul_wide(min), max.mul_wide(max));
+    assert_eq!(max.mul_wide(Tryte::ZERO), Tryte::<12>::ZERO);
+
+    let a = Tryte::<6>::from_i64(-121);
+    let b = Tryte::<6>::from_i64(245);
+    assert_eq!(a.mul_wide(b).to_i64(), -29_645);
+    let wider: Tryte<20> = a.widening_mul(b);
+    assert_eq!(wider.to_i64(), -29_645);
+
+    let max20 = Tryte::<20>::MAX;
+    let product: Tryte<40> = max20.widening_mul(max20);
+    assert_eq!(product.to_i64(), max20.to_i64() * max20.to_i64());
+}
+
+#[cfg(test)]
+#[test]
+pub fn test_tryte_display_padding() {
+    use alloc::format;
+
+    let tryte = Tryte::<6>::from("-0+");
+    assert_eq!(format!("{}", tryte), "000-0+");
+    assert_eq!(format!("{:4}", tryte), "000-0+");
+    assert_eq!(format!("{:10}", tryte), "000-0+    ");
+    assert_eq!(format!("{:>10}", tryte), "    000-0+");
+    assert_eq!(format!("{:^10}", tryte), "  000-0+  ");
+    assert_eq!(format!("{:*>8}", tryte), "**000-0+");
+    assert_eq!(format!("{:#}", tryte), "000-0+");
+    assert_eq!(format!("{:#4}", tryte), "000-0+");
+    assert_eq!(format!("{:#9}", tryte), "000000-0+");
+    assert_eq!(format!("{:#6}", Tryte::<3>::MIN), "000---");
+    assert_eq!(format!("{:#<6}", Tryte::<3>::MIN), "---###");
+}
+
+#[cfg(test)]
+#[test]
+pub fn test_tryte_value() {
+    // `value` reads the array of digits and never allocates, unlike `to_ternary().to_dec()`.
+    for i in -364..=364 {
+        let tryte = Tryte::<6>::from_i64(i);
+        assert_eq!(tryte.value(), i);
+        assert_eq!(tryte.value(), tryte.to_ternary().to_dec());
+        assert_eq!(i64::from(tryte), i);
+    }
+    assert_eq!(Tryte::<40>::MAX.value(), Tryte::<40>::MAX.to_ternary().to_dec());
+    assert_eq!(Tryte::<40>::MIN.value(), -6_078_832_729_528_464_400);
+    assert_eq!(Tryte::<0>::ZERO.value(), 0);
+}
+
+#[cfg(test)]
+#[test]
+pub fn test_tryte_checked_to_dec() {
+    assert_eq!(Tryte::<40>::MAX.checked_to_dec(), Some(Tryte::<40>::MAX.to_i64()));
+    assert_eq!(Tryte::<40>::MIN.checked_to_dec(), Some(-6_078_832_729_528_464_400));
+    assert_eq!(Tryte::<6>::from_i64(-300).checked_to_dec(), Some(-300));
+    assert_eq!(Tryte::<40>::from_i64(1_000).checked_to_dec(), Some(1_000));
+    assert_eq!(Tryte::<1>::ZERO.checked_to_dec(), Some(0));
+}
+
+#[cfg(test)]
+#[test]
+pub fn test_tryte_reverse_digits() {
+    use crate::{Neg, Pos, Zero};
+
+    let tryte = Tryte::<6>::new([Pos, Pos, Zero, Neg, Zero, Zero]);
+    let reversed = tryte.reverse_digits();
+    assert_eq!(reversed.as_array(), &[Zero, Zero, Neg, Zero, Pos, Pos]);
+    assert_eq!(tryte.to_i64(), 243 + 81 - 9);
+    assert_eq!(reversed.to_i64(), -27 + 3 + 1);
+    assert_eq!(reversed.reverse_digits(), tryte);
+    assert_eq!(Tryte::<6>::ZERO.reverse_digits(), Tryte::<6>::ZERO);
+    assert_eq!(Tryte::<5>::from("+-0-+").reverse_digits(), Tryte::<5>::from("+-0-+"));
+}
+
+#[cfg(test)]
+#[test]
+pub fn test_tryte_resize() {
+    assert!(Tryte::<3>::fits_in::<6>());
+    assert!(Tryte::<6>::fits_in::<6>());
+    assert!(!Tryte::<6>::fits_in::<3>());
+
+    for value in -13..=13 {
+        let tryte = Tryte::<3>::from_i64(value);
+        let wide: Tryte<6> = tryte.resize();
+        assert_eq!(wide, Tryte::<6>::from_i64(value));
+        assert_eq!(wide.resize::<3>(), tryte);
+    }
+    assert_eq!(Tryte::<3>::MAX.resize::<6>().to_string(), "000+++");
+    assert_eq!(Tryte::<3>::MIN.resize::<3>(), Tryte::<3>::MIN);
+    assert_eq!(Tryte::<6>::ZERO.resize::<0>(), Tryte::<0>::ZERO);
+}
+
+#[cfg(test)]
+#[test]
+#[should_panic]
+pub fn test_tryte_resize_narrowing_panic() {
+    let _ = Tryte::<6>::from_i64(14).resize::<3>();
+}
+
+#[cfg(test)]
+#[test]
+pub fn test_tryte_try_from_str() {
+    assert_eq!(Tryte::<6>::try_from_str("+0-"), Ok(Tryte::<6>::from("+0-")));
+    assert_eq!(Tryte::<3>::try_from_str("+0-"), Ok(Tryte::<3>::from("+0-")));
+    assert_eq!(Tryte::<3>::try_from_str(""), Ok(Tryte::<3>::ZERO));
+    assert_eq!(Tryte::<3>::try_from_str("+0-+"), Err(TryteError::TooLong));
+    assert_eq!(Tryte::<3>::try_from_str("000+"), Err(TryteError::TooLong));
+    assert_eq!(Tryte::<3>::try_from_str("+1-"), Err(TryteError::InvalidChar));
+    // An invalid character is reported before the length.
+    assert_eq!(Tryte::<3>::try_from_str("+0-x"), Err(TryteError::InvalidChar));
+}
+
+#[cfg(test)]
+#[test]
+pub fn test_tryte_from_ternary_saturating() {
+    assert_eq!(Tryte::<6>::from_ternary_saturating(&Ternary::from_dec(1000)), Tryte::MAX);
+    assert_eq!(Tryte::<6>::from_ternary_saturating(&Ternary::from_dec(1000)).to_i64(), 364);
+    assert_eq!(Tryte::<6>::from_ternary_saturating(&Ternary::from_dec(-1000)).to_i64(), -364);
+    assert_eq!(Tryte::<6>::from_ternary_saturating(&Ternary::from_dec(365)), Tryte::MAX);
+    assert_eq!(Tryte::<6>::from_ternary_saturating(&Ternary::from_dec(364)), Tryte::MAX);
+    assert_eq!(Tryte::<6>::from_ternary_saturating(&Ternary::parse("000-+")).to_i64(), -2);
+    assert_eq!(Tryte::<6>::from_ternary_saturating(&Ternary::parse("")), Tryte::ZERO);
+    assert_eq!(Tryte::<6>::from_ternary_saturating(&Ternary::parse("0000000")), Tryte::ZERO);
+    assert_eq!(
+        Tryte::<3>::from_ternary_saturating(&Ternary::parse(&"-".repeat(100))),
+        Tryte::MIN
+    );
+    for value in -364..=364 {
+        let ternary = Ternary::from_dec(value);
+        assert_eq!(Tryte::<6>::from_ternary_saturating(&ternary), Tryte::from_ternary(&ternary));
+    }
+}
+
+#[cfg(test)]
+#[test]
+pub fn test_tryte_all() {
+    assert_eq!(Tryte::<3>::all().count(), 27);
+    assert_eq!(Tryte::<3>::all().next(), Some(Tryte::MIN));
+    assert_eq!(Tryte::<3>::all().last(), Some(Tryte::MAX));
+    assert!(Tryte::<6>::all().map(|t| t.to_i64()).eq(-364..=364));
+    assert_eq!(Tryte::<0>::all().count(), 1);
+}
+
+#[cfg(test)]
+#[cfg(feature = "serde")]
+#[test]
+pub fn test_tryte_serde() {
+    use serde_test::{assert_de_tokens_error, assert_tokens, Token};
+
+    assert_tokens(&Tryte::<6>::from_i64(6), &[Token::Str("000+-0")]);
+    assert_tokens(&Tryte::<12>::from_i64(-42), &[Token::Str("0000000-+++0")]);
+    assert_de_tokens_error::<Tryte<3>>(
+        &[Token::Str("+-0+")],
+        &Tryte::<3>::try_from_str("+-0+").unwrap_err().to_string(),
+    );
+}
+
+#[cfg(test)]
+#[test]
+pub fn test_tryte_ord() {
+    let mut trytes: Vec<Tryte<6>> = [0, 42, -1, 364, -364, 5, -42, 1, -5, 0]
+        .iter()
+        .map(|v| Tryte::from_i64(*v))
+        .collect();
+    let mut by_value = trytes.clone();
+    by_value.sort_by_key(|t| t.to_i64());
+    trytes.sort();
+    assert_eq!(trytes, by_value);
+    assert_eq!(trytes.first(), Some(&Tryte::MIN));
+    assert_eq!(trytes.last(), Some(&Tryte::MAX));
+    assert_eq!(Tryte::<6>::ZERO.cmp(&Tryte::from_i64(0)), core::cmp::Ordering::Equal);
+    assert!(Tryte::<3>::all().is_sorted());
+}
+
+#[cfg(test)]
+#[test]
+pub fn test_tryte_shift() {
+    let tryte = Tryte::<6>::from("-0+00+");
+
+    // The most significant digit is dropped, unlike with a multiplication by 3.
+    assert_eq!((tryte << 1).to_string(), "0+00+0");
+    assert_eq!((tryte << 1).to_i64(), tryte.to_i64() * 3 + 729);
+    assert_eq!((tryte >> 1).to_string(), "0-0+00");
+    assert_eq!((tryte >> 3) << 3, Tryte::from("-0+000"));
+    assert_eq!(tryte << 0, tryte);
+    assert_eq!(tryte >> 0, tryte);
+
+    assert_eq!(tryte << 6, Tryte::ZERO);
+    assert_eq!(Tryte::<6>::MAX << 6, Tryte::ZERO);
+    assert_eq!(tryte >> 6, Tryte::ZERO);
+    assert_eq!(tryte << 100, Tryte::ZERO);
+}
+
+#[cfg(test)]
+#[test]
+pub fn test_tryte_try_from_ternary() {
+    use crate::ter;
+
+    assert_eq!(Tryte::<6>::try_from(&ter("+0-0+-")), Ok(Tryte::from("+0-0+-")));
+    assert_eq!(Tryte::<6>::try_from(&ter("-")), Ok(Tryte::from_i64(-1)));
+    assert_eq!(Tryte::<6>::try_from(&ter("")), Ok(Tryte::ZERO));
+
+    let error = Tryte::<6>::try_from(&ter("+0-0+-+")).unwrap_err();
+    assert_eq!(error, TryteFromTernaryError { length: 7, size: 6 });
+    assert_eq!(error.to_string(), "cannot fit 7 digits into a Tryte<6>");
+    assert!(Tryte::<6>::try_from(&ter("0000000")).is_err());
+}
+
+#[cfg(test)]
+#[test]
+pub fn test_tryte_wrapping_ops() {
+    let one = Tryte::<6>::from_i64(1);
+    let two = Tryte::<6>::from_i64(2);
+
+    assert_eq!(Tryte::<6>::MAX.wrapping_add(one), Tryte::MIN);
+    assert_eq!(Tryte::<6>::MAX.overflowing_add(one), (Tryte::MIN, true));
+    assert_eq!(Tryte::<6>::MAX.overflowing_add(two).0.to_i64(), -363);
+    assert_eq!(Tryte::<6>::MIN.wrapping_sub(one), Tryte::MAX);
+    assert_eq!(Tryte::<6>::MIN.overflowing_sub(one), (Tryte::MAX, true));
+    assert_eq!(Tryte::<6>::MAX.overflowing_mul(two), (Tryte::from_i64(728 - 729), true));
+    assert_eq!(Tryte::<6>::MAX.wrapping_mul(Tryte::MAX).to_i64(), (364 * 364 + 364) % 729 - 364);
+
+    // In range, the result is exact.
+    assert_eq!(Tryte::<6>::from_i64(363).overflowing_add(one), (Tryte::MAX, false));
+    assert_eq!(two.overflowing_sub(Tryte::MAX), (Tryte::from_i64(-362), false));
+    assert_eq!(two.overflowing_mul(Tryte::from_i64(-182)), (Tryte::from_i64(-364), false));
+    // The overflow flag only depends on the exact result.
+    assert!(Tryte::<6>::MAX.overflowing_sub(Tryte::MIN).1);
+    assert_eq!(Tryte::<6>::MAX.overflowing_add(Tryte::MIN), (Tryte::ZERO, false));
+
+    let max = Tryte::<40>::MAX;
+    assert_eq!(max.overflowing_add(max), (Tryte::from_i64(-1), true));
+    assert_eq!(max.overflowing_mul(max), (Tryte::from_i64(-3_039_416_364_764_232_200), true));
+}